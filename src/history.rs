@@ -0,0 +1,105 @@
+use crate::rendering::ChunkUpdateEvent;
+use crate::{map::TileType, GameMap, GameState};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// Oldest undo entries are dropped past this depth so the history can't grow unbounded over a
+/// long play session.
+const MAX_HISTORY_DEPTH: usize = 100;
+
+/// One tile write `stamp_at` actually performed: which layer, which flattened index, and the
+/// value before/after. `old`/`new` let undo and redo replay the same change in either direction.
+#[derive(Clone, Copy)]
+pub struct TileChange {
+    pub layer_id: i32,
+    pub idx: usize,
+    pub old: TileType,
+    pub new: TileType,
+}
+
+/// All tile writes from a single `apply_tool` drag, undone/redone atomically as one unit.
+pub type Edit = Vec<TileChange>;
+
+/// Undo/redo stacks of `Edit`s. `apply_tool` accumulates one `Edit` per drag and pushes it via
+/// `push` on mouse-up; `undo_redo_system` pops from either stack and replays the tile writes.
+#[derive(Resource, Default)]
+pub struct EditHistory {
+    undo_stack: Vec<Edit>,
+    redo_stack: Vec<Edit>,
+}
+
+impl EditHistory {
+    /// Pushes a completed drag's changes onto the undo stack and clears the redo stack, matching
+    /// the usual editor convention that a fresh edit invalidates any redo history. Empty edits
+    /// (a drag that touched nothing) are dropped rather than stored.
+    pub fn push(&mut self, edit: Edit) {
+        if edit.is_empty() {
+            return;
+        }
+        self.undo_stack.push(edit);
+        if self.undo_stack.len() > MAX_HISTORY_DEPTH {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+}
+
+/// Writes every change in `edit` back into `map` (its `old` value if `reverse`, else its `new`
+/// value), returning the set of chunks touched so the caller can emit `ChunkUpdateEvent`s.
+fn replay(map: &mut GameMap, width: u32, edit: &Edit, reverse: bool) -> HashSet<(u32, u32)> {
+    let mut chunks = HashSet::new();
+    for change in edit {
+        if let Some(layer) = map.layers.get_mut(&change.layer_id) {
+            layer[change.idx] = if reverse { change.old } else { change.new };
+            let x = (change.idx as u32) % width;
+            let z = (change.idx as u32) / width;
+            chunks.insert((x / 32, z / 32));
+        }
+    }
+    chunks
+}
+
+/// Ctrl+Z pops the undo stack and reverses its tile writes; Ctrl+Y pops the redo stack and
+/// reapplies them. Both push the popped `Edit` onto the other stack so undo/redo toggle cleanly.
+fn undo_redo_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut history: ResMut<EditHistory>,
+    mut map: ResMut<GameMap>,
+    mut chunk_events: EventWriter<ChunkUpdateEvent>,
+) {
+    let ctrl = keys.pressed(KeyCode::ControlLeft) || keys.pressed(KeyCode::ControlRight);
+    if !ctrl {
+        return;
+    }
+
+    let width = map.width;
+
+    if keys.just_pressed(KeyCode::KeyZ) {
+        if let Some(edit) = history.undo_stack.pop() {
+            let chunks = replay(&mut map, width, &edit, true);
+            history.redo_stack.push(edit);
+            for (chunk_x, chunk_z) in chunks {
+                chunk_events.send(ChunkUpdateEvent { chunk_x, chunk_z });
+            }
+        }
+    } else if keys.just_pressed(KeyCode::KeyY) {
+        if let Some(edit) = history.redo_stack.pop() {
+            let chunks = replay(&mut map, width, &edit, false);
+            history.undo_stack.push(edit);
+            for (chunk_x, chunk_z) in chunks {
+                chunk_events.send(ChunkUpdateEvent { chunk_x, chunk_z });
+            }
+        }
+    }
+}
+
+pub struct HistoryPlugin;
+
+impl Plugin for HistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditHistory>().add_systems(
+            Update,
+            undo_redo_system.run_if(in_state(GameState::Game)),
+        );
+    }
+}
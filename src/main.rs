@@ -1,11 +1,15 @@
 use bevy::prelude::*;
 use bevy_egui::EguiPlugin;
 
+mod history;
 mod map;
+mod persistence;
 mod rendering;
 mod simulation;
+mod traffic;
 mod ui;
 
+use history::{EditHistory, HistoryPlugin};
 use map::MapPlugin;
 use rendering::{ChunkUpdateEvent, CursorMapPosition, RenderingPlugin};
 use simulation::SimulationPlugin;
@@ -25,6 +29,62 @@ pub enum GameState {
 struct DragState {
     start: Option<[i32; 2]>,
     current: Option<[i32; 2]>,
+    /// Changes stamped so far this drag under `ui::DrawMode::Freehand`, where every frame paints
+    /// immediately instead of computing a shape from `start`/`current` on release.
+    freehand_edit: history::Edit,
+    freehand_chunks: HashSet<(u32, u32)>,
+    /// Last cell painted in the current freehand stroke, used to infer a one-way road's
+    /// direction (a single point has no orientation of its own).
+    freehand_last: Option<[i32; 2]>,
+}
+
+/// Clamps `end` to whichever axis dominates the drag from `start`, turning a rectangle/diagonal
+/// drag into a straight horizontal or vertical line. Used by `ui::DrawMode::AxisLock`.
+fn axis_lock(start: [i32; 2], end: [i32; 2]) -> [i32; 2] {
+    let dx = (end[0] - start[0]).abs();
+    let dz = (end[1] - start[1]).abs();
+    if dx >= dz {
+        [end[0], start[1]]
+    } else {
+        [start[0], end[1]]
+    }
+}
+
+/// Resolves the tile a non-Fill tool stamps at a single point, used by freehand painting where
+/// there's no start/end rectangle to derive it from. Road tools infer direction from `prev` (the
+/// previous cell painted this stroke); `cur` is only consulted then.
+fn freehand_tile_type(
+    tool: &ToolState,
+    prev: Option<[i32; 2]>,
+    cur: [i32; 2],
+) -> Option<map::TileType> {
+    if is_road_tool(tool) {
+        let (horizontal, increasing_x, increasing_z) = match prev {
+            Some(p) => {
+                let dx = cur[0] - p[0];
+                let dz = cur[1] - p[1];
+                (dx.abs() >= dz.abs(), dx >= 0, dz >= 0)
+            }
+            None => (true, true, true),
+        };
+        Some(road_tile_type(tool, horizontal, increasing_x, increasing_z))
+    } else {
+        match tool {
+            ToolState::Bulldozer => Some(map::TileType::Dirt),
+            ToolState::Residential => Some(map::TileType::Residential),
+            ToolState::Commercial => Some(map::TileType::Commercial),
+            ToolState::Industrial => Some(map::TileType::Industrial),
+            ToolState::PowerPlantCoal => Some(map::TileType::PowerPlantCoal),
+            ToolState::PowerPlantGas => Some(map::TileType::PowerPlantGas),
+            ToolState::PowerPlantNuclear => Some(map::TileType::PowerPlantNuclear),
+            ToolState::PowerPlantSolar => Some(map::TileType::PowerPlantSolar),
+            ToolState::PowerPlantWind => Some(map::TileType::PowerPlantWind),
+            ToolState::PowerLine => Some(map::TileType::PowerLine),
+            ToolState::Subway => Some(map::TileType::Subway),
+            ToolState::Pipe => Some(map::TileType::Pipe),
+            _ => None,
+        }
+    }
 }
 
 fn main() {
@@ -44,11 +104,20 @@ fn main() {
         ))
         .init_state::<GameState>()
         .init_resource::<DragState>() // DragState
-        .add_plugins((MapPlugin, UiPlugin, SimulationPlugin, RenderingPlugin))
+        .add_plugins((
+            MapPlugin,
+            UiPlugin,
+            SimulationPlugin,
+            RenderingPlugin,
+            HistoryPlugin,
+            traffic::TrafficPlugin,
+        ))
         .add_systems(Update, exit_on_esc)
         .add_systems(
             Update,
-            (handle_interaction, draw_preview_gizmos).run_if(in_state(GameState::Game)),
+            (ui::update_draw_mode_system, handle_interaction, draw_preview_gizmos)
+                .chain()
+                .run_if(in_state(GameState::Game)),
         )
         .run();
 }
@@ -60,8 +129,19 @@ fn exit_on_esc(mut exit: EventWriter<AppExit>, keyboard_input: Res<ButtonInput<K
 }
 
 // Draw Preview Gizmos for Dragging
-fn draw_preview_gizmos(drag: Res<DragState>, tool: Res<ToolState>, mut gizmos: Gizmos) {
+fn draw_preview_gizmos(
+    drag: Res<DragState>,
+    tool: Res<ToolState>,
+    brush_size: Res<ui::BrushSize>,
+    draw_mode: Res<ui::DrawMode>,
+    mut gizmos: Gizmos,
+) {
     if let (Some(start), Some(curr)) = (drag.start, drag.current) {
+        let curr = if *draw_mode == ui::DrawMode::AxisLock {
+            axis_lock(start, curr)
+        } else {
+            curr
+        };
         let sx = start[0];
         let sz = start[1];
         let cx = curr[0];
@@ -69,40 +149,47 @@ fn draw_preview_gizmos(drag: Res<DragState>, tool: Res<ToolState>, mut gizmos: G
 
         let color = match *tool {
             ToolState::Road => Color::srgb(0.5, 0.5, 0.5), // Grey
+            ToolState::Avenue => Color::srgb(0.6, 0.5, 0.3), // Tan
+            ToolState::Highway => Color::srgb(0.3, 0.3, 0.4), // Dark slate
+            ToolState::RoadOneWay => Color::srgb(0.4, 0.6, 0.4), // Olive
             ToolState::Residential => Color::srgb(0.0, 1.0, 0.0), // Green
             ToolState::Commercial => Color::srgb(0.0, 0.0, 1.0), // Blue
             ToolState::Industrial => Color::srgb(1.0, 1.0, 0.0), // Yellow
-            ToolState::PowerPlant => Color::srgb(1.0, 0.0, 0.0), // Red
+            ToolState::PowerPlantCoal
+            | ToolState::PowerPlantGas
+            | ToolState::PowerPlantNuclear
+            | ToolState::PowerPlantSolar
+            | ToolState::PowerPlantWind => Color::srgb(1.0, 0.0, 0.0), // Red
             ToolState::PowerLine => Color::srgb(0.0, 1.0, 1.0), // Cyan
             ToolState::Bulldozer => Color::srgb(1.0, 0.0, 0.0), // Red
+            ToolState::Subway => Color::srgb(0.6, 0.6, 0.7),    // Steel grey
+            ToolState::Pipe => Color::srgb(0.3, 0.5, 0.9),      // Water blue
+            ToolState::Fill => Color::srgb(0.8, 0.4, 0.8),      // Magenta
             _ => Color::WHITE,
         };
 
-        if *tool == ToolState::Road {
-            // Straight Line Logic: Horizontal or Vertical based on major axis
-            let dx = (cx - sx).abs();
-            let dz = (cz - sz).abs();
-
-            if dx > dz {
-                // Horizontal (vary X)
-                let min_x = sx.min(cx);
-                let max_x = sx.max(cx);
-                for x in min_x..=max_x {
-                    gizmos.cuboid(
-                        Transform::from_xyz(x as f32 + 0.5, 1.1, sz as f32 + 0.5),
-                        color,
-                    );
-                }
-            } else {
-                // Vertical (vary Z)
-                let min_z = sz.min(cz);
-                let max_z = sz.max(cz);
-                for z in min_z..=max_z {
-                    gizmos.cuboid(
-                        Transform::from_xyz(sx as f32 + 0.5, 1.1, z as f32 + 0.5),
-                        color,
-                    );
-                }
+        if *tool == ToolState::Fill {
+            // Fill always starts from the tile under the cursor, so preview just that cell.
+            gizmos.cuboid(
+                Transform::from_xyz(sx as f32 + 0.5, 1.1, sz as f32 + 0.5),
+                color,
+            );
+        } else if *draw_mode == ui::DrawMode::Freehand {
+            // Freehand paints the brush footprint under the cursor each frame rather than a
+            // shape from start to end.
+            for (ox, oz) in brush_offsets(brush_size.0) {
+                gizmos.cuboid(
+                    Transform::from_xyz(cx as f32 + ox as f32 + 0.5, 1.1, cz as f32 + oz as f32 + 0.5),
+                    color,
+                );
+            }
+        } else if is_road_tool(tool) {
+            // Diagonal-capable line preview, mirroring `apply_tool`'s Bresenham rasterization.
+            for (x, z) in bresenham_line(sx, sz, cx, cz) {
+                gizmos.cuboid(
+                    Transform::from_xyz(x as f32 + 0.5, 1.1, z as f32 + 0.5),
+                    color,
+                );
             }
         } else {
             // Rectangular Area
@@ -127,12 +214,18 @@ fn handle_interaction(
     mouse: Res<ButtonInput<MouseButton>>,
     cursor: Res<CursorMapPosition>,
     tool: Res<ToolState>,
+    brush_size: Res<ui::BrushSize>,
+    fill_target: Res<ui::FillTarget>,
     mut map: ResMut<GameMap>,
     mut chunk_events: EventWriter<ChunkUpdateEvent>,
     mut drag: ResMut<DragState>,
     mut inspector: ResMut<ui::InspectorState>,
+    mut window_manager: ResMut<ui::WindowManager>,
+    mut history: ResMut<EditHistory>,
+    draw_mode: Res<ui::DrawMode>,
     power_grid: Res<simulation::PowerGrid>,
     sim_state: Res<simulation::SimulationState>,
+    traffic: Res<traffic::TrafficMap>,
     window_query: Query<&Window>,
 ) {
     let cursor_coord = if let (Some(x), Some(z)) = (cursor.x, cursor.z) {
@@ -175,6 +268,21 @@ fn handle_interaction(
                     // Build Details String
                     let mut details = format!("Type: {:?}\nLayer: {}\n", tile, layer_name);
 
+                    if let Some(road) = map::road_properties(tile) {
+                        details.push_str(&format!(
+                            "Road Class: {:?} (capacity {:.1}x, speed {:.2}x, cost {})\n",
+                            road.class, road.capacity, road.speed_multiplier, road.build_cost
+                        ));
+                        if let Some(direction) = road.one_way {
+                            details.push_str(&format!("One-Way: {:?}\n", direction));
+                        }
+                        details.push_str(&format!(
+                            "Traffic: {} agents (congestion {:.0}%)\n",
+                            traffic::tile_agent_count(&traffic, x, z),
+                            traffic::density_at(&traffic, x, z).min(1.0) * 100.0
+                        ));
+                    }
+
                     // Population / Jobs (Approximate based on tile type)
                     // ... (This logic is in census_system, repeated slightly here or just static info)
                     // Status
@@ -186,17 +294,24 @@ fn handle_interaction(
                             "Powered: {}\n",
                             if is_powered { "YES" } else { "NO" }
                         ));
-                    } else if tile == map::TileType::PowerPlant {
-                        details.push_str("Generates: 500 units\n");
+                    } else if simulation::is_power_plant(tile) {
+                        details.push_str(&format!(
+                            "Generates: {} units\n",
+                            simulation::plant_supply(tile)
+                        ));
+                        details.push_str(&format!(
+                            "Pollution: {}\n",
+                            simulation::plant_pollution(tile)
+                        ));
                         details.push_str(&format!("Grid Net Power: {}\n", power_grid.net_power));
                     }
 
                     inspector.tile_info = Some((format!("Inspector ({}, {})", x, z), details));
-                    inspector.visible = true;
+                    window_manager.open_and_raise(ui::WindowId::Inspector);
 
                     if let Ok(window) = window_query.get_single() {
                         if let Some(pos) = window.cursor_position() {
-                            inspector.screen_pos = pos;
+                            inspector.pending_position = Some(pos);
                         }
                     }
                 }
@@ -210,25 +325,224 @@ fn handle_interaction(
         if let Some(coord) = cursor_coord {
             drag.start = Some(coord);
             drag.current = Some(coord);
+            drag.freehand_edit.clear();
+            drag.freehand_chunks.clear();
+            drag.freehand_last = None;
             // Close inspector on interaction
-            inspector.visible = false;
+            window_manager.set_open(ui::WindowId::Inspector, false);
+
+            if *draw_mode == ui::DrawMode::Freehand {
+                if let Some(tile_type) = freehand_tile_type(&tool, None, coord) {
+                    for (ox, oz) in brush_offsets(brush_size.0) {
+                        stamp_at(
+                            &mut *map,
+                            &tool,
+                            tile_type,
+                            coord[0] + ox,
+                            coord[1] + oz,
+                            &mut drag.freehand_chunks,
+                            &mut drag.freehand_edit,
+                        );
+                    }
+                    drag.freehand_last = Some(coord);
+                }
+            }
         }
     }
 
-    // 2. Mouse Hold -> Update Drag
+    // 2. Mouse Hold -> Update Drag (or paint immediately under `DrawMode::Freehand`)
     if mouse.pressed(MouseButton::Left) {
         if let Some(coord) = cursor_coord {
+            let moved = drag.current != Some(coord);
             drag.current = Some(coord);
+
+            if moved && *draw_mode == ui::DrawMode::Freehand {
+                if let Some(tile_type) = freehand_tile_type(&tool, drag.freehand_last, coord) {
+                    for (ox, oz) in brush_offsets(brush_size.0) {
+                        stamp_at(
+                            &mut *map,
+                            &tool,
+                            tile_type,
+                            coord[0] + ox,
+                            coord[1] + oz,
+                            &mut drag.freehand_chunks,
+                            &mut drag.freehand_edit,
+                        );
+                    }
+                    drag.freehand_last = Some(coord);
+                }
+            }
         }
     }
 
     // 3. Mouse Up -> Apply
     if mouse.just_released(MouseButton::Left) {
-        if let (Some(start), Some(curr)) = (drag.start, drag.current) {
-            apply_tool(start, curr, &*tool, &mut *map, &mut chunk_events);
+        if *draw_mode == ui::DrawMode::Freehand {
+            for (cx, cz) in drag.freehand_chunks.drain() {
+                chunk_events.send(ChunkUpdateEvent {
+                    chunk_x: cx,
+                    chunk_z: cz,
+                });
+            }
+            history.push(std::mem::take(&mut drag.freehand_edit));
+        } else if let (Some(start), Some(curr)) = (drag.start, drag.current) {
+            let end = if *draw_mode == ui::DrawMode::AxisLock {
+                axis_lock(start, curr)
+            } else {
+                curr
+            };
+            let edit = apply_tool(
+                start,
+                end,
+                &*tool,
+                brush_size.0,
+                fill_target.0,
+                &mut *map,
+                &mut chunk_events,
+            );
+            history.push(edit);
         }
         drag.start = None;
         drag.current = None;
+        drag.freehand_last = None;
+    }
+}
+
+/// Whether `tool` belongs to the road family, all of which draw as a straight line between the
+/// drag start and end rather than filling a rectangle (see `apply_tool`/`draw_preview_gizmos`).
+fn is_road_tool(tool: &ToolState) -> bool {
+    matches!(
+        tool,
+        ToolState::Road | ToolState::Avenue | ToolState::Highway | ToolState::RoadOneWay
+    )
+}
+
+/// Resolves a road-family tool to the `TileType` it stamps. `horizontal` and the increasing-axis
+/// flags describe the drawn line's orientation, which for `RoadOneWay` also picks the direction
+/// traffic flows (the inspector reads it back via `map::road_properties`).
+fn road_tile_type(
+    tool: &ToolState,
+    horizontal: bool,
+    increasing_x: bool,
+    increasing_z: bool,
+) -> map::TileType {
+    match tool {
+        ToolState::Avenue => map::TileType::Avenue,
+        ToolState::Highway => map::TileType::Highway,
+        ToolState::RoadOneWay => {
+            if horizontal {
+                if increasing_x {
+                    map::TileType::RoadOneWayEast
+                } else {
+                    map::TileType::RoadOneWayWest
+                }
+            } else if increasing_z {
+                map::TileType::RoadOneWaySouth
+            } else {
+                map::TileType::RoadOneWayNorth
+            }
+        }
+        _ => map::TileType::Road,
+    }
+}
+
+/// Offsets (dx, dz) of a square brush footprint centered on the origin, `brush_size` tiles wide
+/// for odd sizes; an even size rounds up to the next odd width (`brush_size + 1`).
+pub(crate) fn brush_offsets(brush_size: u32) -> impl Iterator<Item = (i32, i32)> {
+    let radius = (brush_size as i32) / 2;
+    (-radius..=radius).flat_map(move |dz| (-radius..=radius).map(move |dx| (dx, dz)))
+}
+
+/// Bresenham's line algorithm: every grid cell from `(sx, sz)` to `(ex, ez)` inclusive, in a
+/// single 4/8-connected stepped path. Used by `apply_tool` so a road drag can run diagonally
+/// instead of only along the major axis.
+fn bresenham_line(sx: i32, sz: i32, ex: i32, ez: i32) -> Vec<(i32, i32)> {
+    let mut points = Vec::new();
+    let dx = (ex - sx).abs();
+    let dz = -(ez - sz).abs();
+    let sx_step = if sx < ex { 1 } else { -1 };
+    let sz_step = if sz < ez { 1 } else { -1 };
+    let mut err = dx + dz;
+    let (mut x, mut z) = (sx, sz);
+
+    loop {
+        points.push((x, z));
+        if x == ex && z == ez {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dz {
+            err += dz;
+            x += sx_step;
+        }
+        if e2 <= dx {
+            err += dx;
+            z += sz_step;
+        }
+    }
+
+    points
+}
+
+/// 4-connected flood fill from `(start_x, start_z)` on the surface layer: every tile reachable
+/// from the start through same-typed neighbors is repainted to `target`, using an explicit stack
+/// (rather than recursion) so large contiguous regions can't blow the call stack.
+fn flood_fill(
+    map: &mut GameMap,
+    start_x: i32,
+    start_z: i32,
+    target: map::TileType,
+    affected_chunks: &mut HashSet<(u32, u32)>,
+    edit: &mut history::Edit,
+) {
+    let (w, h) = (map.width as i32, map.height as i32);
+    if start_x < 0 || start_x >= w || start_z < 0 || start_z >= h {
+        return;
+    }
+
+    let Some(surface) = map.layers.get(&0) else {
+        return;
+    };
+    let source = surface[(start_z * w + start_x) as usize];
+    if source == target {
+        return;
+    }
+
+    let mut visited = vec![false; (w * h) as usize];
+    let mut stack = vec![(start_x, start_z)];
+
+    while let Some((x, z)) = stack.pop() {
+        if x < 0 || x >= w || z < 0 || z >= h {
+            continue;
+        }
+        let idx = (z * w + x) as usize;
+        if visited[idx] {
+            continue;
+        }
+        visited[idx] = true;
+
+        let Some(layer) = map.layers.get(&0) else {
+            continue;
+        };
+        if layer[idx] != source {
+            continue;
+        }
+
+        if let Some(layer) = map.layers.get_mut(&0) {
+            layer[idx] = target;
+        }
+        affected_chunks.insert(((x as u32) / 32, (z as u32) / 32));
+        edit.push(history::TileChange {
+            layer_id: 0,
+            idx,
+            old: source,
+            new: target,
+        });
+
+        stack.push((x + 1, z));
+        stack.push((x - 1, z));
+        stack.push((x, z + 1));
+        stack.push((x, z - 1));
     }
 }
 
@@ -236,53 +550,31 @@ fn apply_tool(
     start: [i32; 2],
     end: [i32; 2],
     tool: &ToolState,
+    brush_size: u32,
+    fill_target: map::TileType,
     map: &mut GameMap,
     chunk_events: &mut EventWriter<ChunkUpdateEvent>,
-) {
+) -> history::Edit {
     let mut affected_chunks = HashSet::new();
+    let mut edit = history::Edit::new();
     let sx = start[0];
     let sz = start[1];
     let ex = end[0];
     let ez = end[1];
 
-    // Helper closure removed to avoid borrow issues
-    // Using inline logic
-
-    if *tool == ToolState::Road {
-        // Straight Line Logic
+    if *tool == ToolState::Fill {
+        flood_fill(map, sx, sz, fill_target, &mut affected_chunks, &mut edit);
+    } else if is_road_tool(tool) {
+        // Diagonal-capable line via Bresenham; the overall horizontal/vertical bias still picks
+        // the one-way direction for `ToolState::RoadOneWay`.
         let dx = (ex - sx).abs();
         let dz = (ez - sz).abs();
+        let horizontal = dx > dz;
+        let tile_type = road_tile_type(tool, horizontal, ex >= sx, ez >= sz);
 
-        if dx > dz {
-            // Horizontal
-            let min_x = sx.min(ex);
-            let max_x = sx.max(ex);
-            for x in min_x..=max_x {
-                // Inline set_tile
-                if x >= 0 && x < map.width as i32 && sz >= 0 && sz < map.height as i32 {
-                    let idx = (sz * map.width as i32 + x) as usize;
-                    if let Some(layer) = map.layers.get_mut(&0) {
-                        if layer[idx] != map::TileType::Road {
-                            layer[idx] = map::TileType::Road;
-                            affected_chunks.insert(((x as u32) / 32, (sz as u32) / 32));
-                        }
-                    }
-                }
-            }
-        } else {
-            // Vertical
-            let min_z = sz.min(ez);
-            let max_z = sz.max(ez);
-            for z in min_z..=max_z {
-                if sx >= 0 && sx < map.width as i32 && z >= 0 && z < map.height as i32 {
-                    let idx = (z * map.width as i32 + sx) as usize;
-                    if let Some(layer) = map.layers.get_mut(&0) {
-                        if layer[idx] != map::TileType::Road {
-                            layer[idx] = map::TileType::Road;
-                            affected_chunks.insert(((sx as u32) / 32, (z as u32) / 32));
-                        }
-                    }
-                }
+        for (px, pz) in bresenham_line(sx, sz, ex, ez) {
+            for (ox, oz) in brush_offsets(brush_size) {
+                stamp_at(map, tool, tile_type, px + ox, pz + oz, &mut affected_chunks, &mut edit);
             }
         }
     } else {
@@ -297,75 +589,21 @@ fn apply_tool(
             ToolState::Residential => map::TileType::Residential,
             ToolState::Commercial => map::TileType::Commercial,
             ToolState::Industrial => map::TileType::Industrial,
-            ToolState::PowerPlant => map::TileType::PowerPlant,
+            ToolState::PowerPlantCoal => map::TileType::PowerPlantCoal,
+            ToolState::PowerPlantGas => map::TileType::PowerPlantGas,
+            ToolState::PowerPlantNuclear => map::TileType::PowerPlantNuclear,
+            ToolState::PowerPlantSolar => map::TileType::PowerPlantSolar,
+            ToolState::PowerPlantWind => map::TileType::PowerPlantWind,
             ToolState::PowerLine => map::TileType::PowerLine, // Handled specifically below for Air Layer
-            _ => return,
+            ToolState::Subway => map::TileType::Subway, // Handled specifically below for Underground Layer
+            ToolState::Pipe => map::TileType::Pipe, // Handled specifically below for Underground Layer
+            _ => return edit,
         };
 
         for z in min_z..=max_z {
             for x in min_x..=max_x {
-                if *tool == ToolState::PowerLine {
-                    if x >= 0 && x < map.width as i32 && z >= 0 && z < map.height as i32 {
-                        let idx = (z * map.width as i32 + x) as usize;
-                        // Determine target layer: Air for lines, Surface for others
-                        if let Some(layers) = map.layers.get_mut(&1) {
-                            // Need to check surface layer (0) but also accessing layer 1
-                            // map.layers borrows map. We need disjoint borrows or just clone tile type?
-                            // Since we are inside `layers` mutable borrow, we can't easily get reference to layer 0.
-                            // Solution: Check layer 0 first, get bool, then mutate layer 1.
-
-                            let is_blocked = false;
-                            // Just check map.layers.get(&0) is complicated by borrow checker if we hold a mut ref to layers(&1) from same HashMap
-                            // Wait, `map.layers` is `HashMap`. `get_mut` borrows the map. We can't query it again.
-                            // We should get both layers out if possible or iterate differently.
-                            // Or just assume empty if we can't check?
-                            // Actually, since we are doing one tile at a time, we *could* do it but it's inefficient.
-                            // Better: Split the scope.
-
-                            // 1. Check blockage
-                            // We need to release `map.layers` borrow to check layer 0.
-                        }
-
-                        // Revised Logic:
-                        let mut blocked = false;
-                        if let Some(surface) = map.layers.get(&0) {
-                            let s_idx = (z * map.width as i32 + x) as usize;
-                            let surface_tile = surface[s_idx];
-                            if matches!(
-                                surface_tile,
-                                map::TileType::ResidentialOccupied1
-                                    | map::TileType::ResidentialOccupied2
-                                    | map::TileType::ResidentialOccupied3
-                                    | map::TileType::CommercialOccupied1
-                                    | map::TileType::CommercialOccupied2
-                                    | map::TileType::CommercialOccupied3
-                                    | map::TileType::IndustrialOccupied1
-                                    | map::TileType::IndustrialOccupied2
-                                    | map::TileType::IndustrialOccupied3
-                                    | map::TileType::PowerPlant
-                            ) {
-                                blocked = true;
-                            }
-                        }
-
-                        if !blocked {
-                            if let Some(air) = map.layers.get_mut(&1) {
-                                let s_idx = (z * map.width as i32 + x) as usize;
-                                air[s_idx] = map::TileType::PowerLine;
-                                affected_chunks.insert(((x as u32) / 32, (z as u32) / 32));
-                            }
-                        }
-                    }
-                } else {
-                    if x >= 0 && x < map.width as i32 && z >= 0 && z < map.height as i32 {
-                        let idx = (z * map.width as i32 + x) as usize;
-                        if let Some(layer) = map.layers.get_mut(&0) {
-                            if layer[idx] != tile_type {
-                                layer[idx] = tile_type;
-                                affected_chunks.insert(((x as u32) / 32, (z as u32) / 32));
-                            }
-                        }
-                    }
+                for (ox, oz) in brush_offsets(brush_size) {
+                    stamp_at(map, tool, tile_type, x + ox, z + oz, &mut affected_chunks, &mut edit);
                 }
             }
         }
@@ -377,4 +615,155 @@ fn apply_tool(
             chunk_z: cz,
         });
     }
+
+    edit
+}
+
+/// Sets a single `(x, z)` tile for `tool`, picking its target layer and enforcing the same
+/// placement rules as `tool_placement_valid`. Called once per tile in the brush footprint; any
+/// tile it actually changes is pushed onto `edit` so `EditHistory` can undo the whole drag later.
+fn stamp_at(
+    map: &mut GameMap,
+    tool: &ToolState,
+    tile_type: map::TileType,
+    x: i32,
+    z: i32,
+    affected_chunks: &mut HashSet<(u32, u32)>,
+    edit: &mut history::Edit,
+) {
+    if x < 0 || x >= map.width as i32 || z < 0 || z >= map.height as i32 {
+        return;
+    }
+    let idx = (z * map.width as i32 + x) as usize;
+
+    if matches!(tool, ToolState::Subway | ToolState::Pipe) {
+        if let Some(underground) = map.layers.get_mut(&-1) {
+            if underground[idx] != tile_type {
+                let old = underground[idx];
+                underground[idx] = tile_type;
+                affected_chunks.insert(((x as u32) / 32, (z as u32) / 32));
+                edit.push(history::TileChange {
+                    layer_id: -1,
+                    idx,
+                    old,
+                    new: tile_type,
+                });
+            }
+        }
+    } else if *tool == ToolState::PowerLine {
+        // Power lines route through the Air layer but are blocked by whatever already occupies
+        // the Surface layer beneath them.
+        let mut blocked = false;
+        if let Some(surface) = map.layers.get(&0) {
+            if matches!(
+                surface[idx],
+                map::TileType::ResidentialOccupied1
+                    | map::TileType::ResidentialOccupied2
+                    | map::TileType::ResidentialOccupied3
+                    | map::TileType::CommercialOccupied1
+                    | map::TileType::CommercialOccupied2
+                    | map::TileType::CommercialOccupied3
+                    | map::TileType::IndustrialOccupied1
+                    | map::TileType::IndustrialOccupied2
+                    | map::TileType::IndustrialOccupied3
+                    | map::TileType::PowerPlantCoal
+                    | map::TileType::PowerPlantGas
+                    | map::TileType::PowerPlantNuclear
+                    | map::TileType::PowerPlantSolar
+                    | map::TileType::PowerPlantWind
+            ) {
+                blocked = true;
+            }
+        }
+
+        if !blocked {
+            if let Some(air) = map.layers.get_mut(&1) {
+                if air[idx] != tile_type {
+                    let old = air[idx];
+                    air[idx] = tile_type;
+                    affected_chunks.insert(((x as u32) / 32, (z as u32) / 32));
+                    edit.push(history::TileChange {
+                        layer_id: 1,
+                        idx,
+                        old,
+                        new: tile_type,
+                    });
+                }
+            }
+        }
+    } else if tool_placement_valid(map, *tool, x, z) {
+        if let Some(layer) = map.layers.get_mut(&0) {
+            if layer[idx] != tile_type {
+                let old = layer[idx];
+                layer[idx] = tile_type;
+                affected_chunks.insert(((x as u32) / 32, (z as u32) / 32));
+                edit.push(history::TileChange {
+                    layer_id: 0,
+                    idx,
+                    old,
+                    new: tile_type,
+                });
+            }
+        }
+    }
+}
+
+fn adjacent_to_road(tiles: &[map::TileType], w: i32, h: i32, x: i32, z: i32) -> bool {
+    for (nx, nz) in [(x + 1, z), (x - 1, z), (x, z + 1), (x, z - 1)] {
+        if nx >= 0 && nx < w && nz >= 0 && nz < h && map::is_road(tiles[(nz * w + nx) as usize]) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether `tool` could legally be placed at `(x, z)` right now — used to tint the hover preview
+/// green/red before the player commits with a click, mirroring the checks `apply_tool` enforces.
+pub(crate) fn tool_placement_valid(map: &GameMap, tool: ToolState, x: i32, z: i32) -> bool {
+    if x < 0 || x >= map.width as i32 || z < 0 || z >= map.height as i32 {
+        return false;
+    }
+    let Some(surface) = map.layers.get(&0) else {
+        return false;
+    };
+    let idx = (z * map.width as i32 + x) as usize;
+    let tile = surface[idx];
+    let (w, h) = (map.width as i32, map.height as i32);
+
+    match tool {
+        ToolState::Select => true,
+        ToolState::Bulldozer => tile != map::TileType::Empty,
+        ToolState::Road | ToolState::Avenue | ToolState::Highway | ToolState::RoadOneWay => {
+            !matches!(tile, map::TileType::Water)
+        }
+        ToolState::Residential | ToolState::Commercial | ToolState::Industrial => {
+            !matches!(tile, map::TileType::Water | map::TileType::Rubble)
+                && adjacent_to_road(surface, w, h, x, z)
+        }
+        ToolState::PowerLine => !matches!(
+            tile,
+            map::TileType::ResidentialOccupied1
+                | map::TileType::ResidentialOccupied2
+                | map::TileType::ResidentialOccupied3
+                | map::TileType::CommercialOccupied1
+                | map::TileType::CommercialOccupied2
+                | map::TileType::CommercialOccupied3
+                | map::TileType::IndustrialOccupied1
+                | map::TileType::IndustrialOccupied2
+                | map::TileType::IndustrialOccupied3
+                | map::TileType::PowerPlantCoal
+                | map::TileType::PowerPlantGas
+                | map::TileType::PowerPlantNuclear
+                | map::TileType::PowerPlantSolar
+                | map::TileType::PowerPlantWind
+        ),
+        ToolState::PowerPlantCoal
+        | ToolState::PowerPlantGas
+        | ToolState::PowerPlantNuclear
+        | ToolState::PowerPlantSolar
+        | ToolState::PowerPlantWind => tile != map::TileType::Water,
+        // Subway/pipe run underground and don't care what's on the surface above them.
+        ToolState::Subway | ToolState::Pipe => true,
+        ToolState::Fill => true,
+    }
 }
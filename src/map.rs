@@ -1,7 +1,8 @@
 use bevy::{prelude::*, utils::HashMap};
 use noise::{NoiseFn, Perlin};
+use rand::Rng;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum TileType {
     #[default]
     Empty,
@@ -20,8 +21,21 @@ pub enum TileType {
     IndustrialOccupied1, // Factory (Level 1)
     IndustrialOccupied2, // Factory (Level 2)
     IndustrialOccupied3, // Factory (Level 3)
+    Rubble,   // Derelict zone left behind by abandonment; must be bulldozed before rebuilding
+    Avenue,   // Higher-capacity road class, above Street (`Road`) and below Highway
+    Highway,  // Widest, fastest road class; rendered spanning beyond its own tile
+    RoadOneWayNorth,
+    RoadOneWayEast,
+    RoadOneWaySouth,
+    RoadOneWayWest,
+    Subway, // Underground (layer -1) rail tunnel
+    Pipe,   // Underground (layer -1) water main
     PowerLine,
-    PowerPlant,
+    PowerPlantCoal,
+    PowerPlantGas,
+    PowerPlantNuclear,
+    PowerPlantSolar,
+    PowerPlantWind,
 }
 
 #[derive(Resource)]
@@ -43,29 +57,106 @@ impl Default for GameMap {
     }
 }
 
+// Base frequency the fBm octaves are built on top of; matches the old single-octave terrain's
+// `scale` so a 1-octave call reproduces its look.
+const BASE_FREQUENCY: f64 = 0.1;
+// Elevation (post-normalization) a cell must clear to be considered a river source, so rivers
+// start in highlands rather than wandering out of the lowlands they're meant to drain into.
+const RIVER_SOURCE_ELEVATION: f64 = 0.7;
+// Tiles on each side of a river's centerline that also get carved to `Water`.
+const RIVER_WIDTH: i32 = 1;
+
 impl GameMap {
-    pub fn new(width: u32, height: u32, water_threshold: f32) -> Self {
+    pub fn new(
+        width: u32,
+        height: u32,
+        water_threshold: f32,
+        octaves: u32,
+        persistence: f32,
+        lacunarity: f32,
+        river_count: u32,
+    ) -> Self {
         let mut layers = HashMap::new();
         let area = (width * height) as usize;
 
-        // Layer 0: Surface
-        let mut surface = Vec::with_capacity(area);
-
         // Layer -1: Underground
         let underground = vec![TileType::Empty; area];
 
         let perlin = Perlin::new(rand::random());
-        let scale = 0.1;
 
-        for y in 0..height {
-            for x in 0..width {
-                let val = perlin.get([x as f64 * scale, y as f64 * scale]);
-                let normalized = (val + 1.0) / 2.0;
+        // 1. Fractal Brownian motion: sum `octaves` layers of Perlin noise, each doubling in
+        // frequency and halving in amplitude (by `lacunarity`/`persistence`), then normalize by
+        // the total amplitude so the result stays in -1..1 regardless of octave count.
+        let mut heights = vec![0.0f64; area];
+        let mut amplitude = 1.0f64;
+        let mut max_amplitude = 0.0f64;
+        for octave in 0..octaves {
+            let frequency = BASE_FREQUENCY * (lacunarity as f64).powi(octave as i32);
+            for y in 0..height {
+                for x in 0..width {
+                    let idx = (y * width + x) as usize;
+                    heights[idx] +=
+                        perlin.get([x as f64 * frequency, y as f64 * frequency]) * amplitude;
+                }
+            }
+            max_amplitude += amplitude;
+            amplitude *= persistence as f64;
+        }
+        for h in heights.iter_mut() {
+            *h = (*h / max_amplitude + 1.0) / 2.0;
+        }
+
+        // 2. Threshold into Water/Dirt. `water_threshold` is also the sea level rivers drain to.
+        let mut surface = vec![TileType::Dirt; area];
+        for (idx, &h) in heights.iter().enumerate() {
+            if h < water_threshold as f64 {
+                surface[idx] = TileType::Water;
+            }
+        }
 
-                if normalized < water_threshold as f64 {
-                    surface.push(TileType::Water);
-                } else {
-                    surface.push(TileType::Dirt);
+        // 3. Carve `river_count` rivers: from a random high-elevation source, walk downhill
+        // (steepest 8-neighbor descent) until hitting water or a local minimum, stamping `Water`
+        // `RIVER_WIDTH` tiles to each side of the path along the way.
+        let mut rng = rand::thread_rng();
+        let sources: Vec<usize> = heights
+            .iter()
+            .enumerate()
+            .filter(|(_, &h)| h >= RIVER_SOURCE_ELEVATION)
+            .map(|(idx, _)| idx)
+            .collect();
+
+        for _ in 0..river_count {
+            if sources.is_empty() {
+                break;
+            }
+            let mut idx = sources[rng.gen_range(0..sources.len())];
+            // Water present before this river started; the river's own stamping doesn't stop it.
+            let existing = surface.clone();
+            // Bounds the walk so a source stuck on a plateau can't loop forever.
+            for _ in 0..(width + height) * 2 {
+                if existing[idx] == TileType::Water {
+                    break;
+                }
+                let x = (idx as u32 % width) as i32;
+                let y = (idx as u32 / width) as i32;
+                stamp_water(&mut surface, width, height, x, y, RIVER_WIDTH);
+
+                let mut next = None;
+                for ny in (y - 1)..=(y + 1) {
+                    for nx in (x - 1)..=(x + 1) {
+                        if (nx, ny) == (x, y) || nx < 0 || nx >= width as i32 || ny < 0 || ny >= height as i32 {
+                            continue;
+                        }
+                        let nidx = (ny as u32 * width + nx as u32) as usize;
+                        if next.map_or(true, |(_, best_h)| heights[nidx] < best_h) {
+                            next = Some((nidx, heights[nidx]));
+                        }
+                    }
+                }
+
+                match next {
+                    Some((nidx, h)) if h < heights[idx] => idx = nidx,
+                    _ => break, // local minimum with nowhere lower to go
                 }
             }
         }
@@ -82,6 +173,86 @@ impl GameMap {
     }
 }
 
+/// Sets every tile within `radius` (Chebyshev distance) of `(cx, cz)` to `Water`.
+fn stamp_water(surface: &mut [TileType], width: u32, height: u32, cx: i32, cz: i32, radius: i32) {
+    for dz in -radius..=radius {
+        for dx in -radius..=radius {
+            let x = cx + dx;
+            let z = cz + dz;
+            if x >= 0 && x < width as i32 && z >= 0 && z < height as i32 {
+                surface[(z as u32 * width + x as u32) as usize] = TileType::Water;
+            }
+        }
+    }
+}
+
+/// Road hierarchy a road `TileType` belongs to, each carrying different gameplay properties —
+/// groundwork for traffic modeling richer than the old single uniform `Road` tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoadClass {
+    Street,
+    Avenue,
+    Highway,
+}
+
+impl RoadClass {
+    /// (relative vehicle capacity, speed multiplier, build cost) for this class.
+    fn base_properties(self) -> (f32, f32, u32) {
+        match self {
+            RoadClass::Street => (1.0, 1.0, 10),
+            RoadClass::Avenue => (2.0, 1.25, 25),
+            RoadClass::Highway => (4.0, 1.75, 60),
+        }
+    }
+}
+
+/// Direction traffic is allowed to flow on a one-way road segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoadDirection {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// Gameplay properties for one road-family tile, looked up via `road_properties`.
+#[derive(Debug, Clone, Copy)]
+pub struct RoadProperties {
+    pub class: RoadClass,
+    pub capacity: f32,
+    pub speed_multiplier: f32,
+    pub build_cost: u32,
+    pub one_way: Option<RoadDirection>,
+}
+
+/// Looks up the class/direction/gameplay properties for any road-family `TileType`, or `None`
+/// if `tile` isn't a road at all.
+pub fn road_properties(tile: TileType) -> Option<RoadProperties> {
+    let (class, one_way) = match tile {
+        TileType::Road => (RoadClass::Street, None),
+        TileType::Avenue => (RoadClass::Avenue, None),
+        TileType::Highway => (RoadClass::Highway, None),
+        TileType::RoadOneWayNorth => (RoadClass::Street, Some(RoadDirection::North)),
+        TileType::RoadOneWayEast => (RoadClass::Street, Some(RoadDirection::East)),
+        TileType::RoadOneWaySouth => (RoadClass::Street, Some(RoadDirection::South)),
+        TileType::RoadOneWayWest => (RoadClass::Street, Some(RoadDirection::West)),
+        _ => return None,
+    };
+    let (capacity, speed_multiplier, build_cost) = class.base_properties();
+    Some(RoadProperties {
+        class,
+        capacity,
+        speed_multiplier,
+        build_cost,
+        one_way,
+    })
+}
+
+/// Whether `tile` is any road-family tile (any `RoadClass`, one-way or not).
+pub fn is_road(tile: TileType) -> bool {
+    road_properties(tile).is_some()
+}
+
 pub struct MapPlugin;
 
 impl Plugin for MapPlugin {
@@ -0,0 +1,249 @@
+use crate::map::TileType;
+use crate::simulation::SimulationState;
+use crate::GameMap;
+use bevy::utils::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+const MAGIC: &[u8; 4] = b"MPRS";
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug)]
+pub enum PersistenceError {
+    Io(io::Error),
+    BadMagic,
+    UnsupportedVersion(u32),
+    Truncated,
+}
+
+impl From<io::Error> for PersistenceError {
+    fn from(err: io::Error) -> Self {
+        PersistenceError::Io(err)
+    }
+}
+
+impl fmt::Display for PersistenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersistenceError::Io(err) => write!(f, "I/O error: {err}"),
+            PersistenceError::BadMagic => write!(f, "not a micropolis-rust save file"),
+            PersistenceError::UnsupportedVersion(version) => {
+                write!(f, "unsupported save format version {version}")
+            }
+            PersistenceError::Truncated => write!(f, "save file is truncated or corrupt"),
+        }
+    }
+}
+
+impl std::error::Error for PersistenceError {}
+
+/// `TileType as u8`, in the exact order the enum is declared in map.rs — `byte_to_tile` is its
+/// inverse. Both must be kept in sync with that declaration order, since it's what the on-disk
+/// tile stream is keyed by.
+const TILE_TABLE: &[TileType] = &[
+    TileType::Empty,
+    TileType::Dirt,
+    TileType::Water,
+    TileType::Road,
+    TileType::Residential,
+    TileType::ResidentialOccupied1,
+    TileType::ResidentialOccupied2,
+    TileType::ResidentialOccupied3,
+    TileType::Commercial,
+    TileType::CommercialOccupied1,
+    TileType::CommercialOccupied2,
+    TileType::CommercialOccupied3,
+    TileType::Industrial,
+    TileType::IndustrialOccupied1,
+    TileType::IndustrialOccupied2,
+    TileType::IndustrialOccupied3,
+    TileType::Rubble,
+    TileType::Avenue,
+    TileType::Highway,
+    TileType::RoadOneWayNorth,
+    TileType::RoadOneWayEast,
+    TileType::RoadOneWaySouth,
+    TileType::RoadOneWayWest,
+    TileType::Subway,
+    TileType::Pipe,
+    TileType::PowerLine,
+    TileType::PowerPlantCoal,
+    TileType::PowerPlantGas,
+    TileType::PowerPlantNuclear,
+    TileType::PowerPlantSolar,
+    TileType::PowerPlantWind,
+];
+
+fn byte_to_tile(byte: u8) -> Option<TileType> {
+    TILE_TABLE.get(byte as usize).copied()
+}
+
+/// Run-length encodes `tiles` as `[run_count: u32][(tile: u8, run_len: u32)...]`. A huge win here
+/// since most of a fresh map is `Empty`/`Dirt` in long unbroken runs.
+fn rle_encode(tiles: &[TileType]) -> Vec<u8> {
+    let mut runs = Vec::new();
+    let mut i = 0;
+    while i < tiles.len() {
+        let byte = tiles[i] as u8;
+        let mut run_len: u32 = 1;
+        let mut j = i + 1;
+        while j < tiles.len() && tiles[j] as u8 == byte && run_len < u32::MAX {
+            run_len += 1;
+            j += 1;
+        }
+        runs.push((byte, run_len));
+        i = j;
+    }
+
+    let mut out = Vec::with_capacity(4 + runs.len() * 5);
+    out.extend_from_slice(&(runs.len() as u32).to_le_bytes());
+    for (byte, run_len) in runs {
+        out.push(byte);
+        out.extend_from_slice(&run_len.to_le_bytes());
+    }
+    out
+}
+
+fn rle_decode(bytes: &[u8], expected_len: usize) -> Result<Vec<TileType>, PersistenceError> {
+    let mut cursor = 0usize;
+    let mut read_u32_at = |cursor: &mut usize| -> Result<u32, PersistenceError> {
+        let slice = bytes
+            .get(*cursor..*cursor + 4)
+            .ok_or(PersistenceError::Truncated)?;
+        *cursor += 4;
+        Ok(u32::from_le_bytes(slice.try_into().unwrap()))
+    };
+
+    let run_count = read_u32_at(&mut cursor)?;
+    let mut tiles = Vec::with_capacity(expected_len);
+    for _ in 0..run_count {
+        let byte = *bytes.get(cursor).ok_or(PersistenceError::Truncated)?;
+        cursor += 1;
+        let run_len = read_u32_at(&mut cursor)?;
+        let tile = byte_to_tile(byte).ok_or(PersistenceError::Truncated)?;
+        tiles.resize(tiles.len() + run_len as usize, tile);
+    }
+
+    if tiles.len() != expected_len {
+        return Err(PersistenceError::Truncated);
+    }
+    Ok(tiles)
+}
+
+fn read_u32(r: &mut impl Read) -> Result<u32, PersistenceError> {
+    let mut buf = [0u8; 4];
+    r.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_i32(r: &mut impl Read) -> Result<i32, PersistenceError> {
+    let mut buf = [0u8; 4];
+    r.read_exact(&mut buf)?;
+    Ok(i32::from_le_bytes(buf))
+}
+
+fn read_i16(r: &mut impl Read) -> Result<i16, PersistenceError> {
+    let mut buf = [0u8; 2];
+    r.read_exact(&mut buf)?;
+    Ok(i16::from_le_bytes(buf))
+}
+
+fn read_u64(r: &mut impl Read) -> Result<u64, PersistenceError> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_f32(r: &mut impl Read) -> Result<f32, PersistenceError> {
+    let mut buf = [0u8; 4];
+    r.read_exact(&mut buf)?;
+    Ok(f32::from_le_bytes(buf))
+}
+
+/// Writes `map` and `sim_state` to `path` as a versioned save file: a header (magic, format
+/// version, width, height, layer count), each layer's id and RLE-encoded tile stream, then the
+/// simulation census/tick fields. `PowerGrid` isn't saved — it's a derived cache that rebuilds
+/// itself from the map once `ChunkUpdateEvent`s fire on load, the same way a new game populates it.
+pub fn save_city(
+    map: &GameMap,
+    sim_state: &SimulationState,
+    path: impl AsRef<Path>,
+) -> Result<(), PersistenceError> {
+    let mut file = File::create(path)?;
+    file.write_all(MAGIC)?;
+    file.write_all(&FORMAT_VERSION.to_le_bytes())?;
+    file.write_all(&map.width.to_le_bytes())?;
+    file.write_all(&map.height.to_le_bytes())?;
+    file.write_all(&(map.layers.len() as u32).to_le_bytes())?;
+
+    let mut layer_ids: Vec<i32> = map.layers.keys().copied().collect();
+    layer_ids.sort();
+    for layer_id in layer_ids {
+        let tiles = &map.layers[&layer_id];
+        let encoded = rle_encode(tiles);
+        file.write_all(&layer_id.to_le_bytes())?;
+        file.write_all(&(encoded.len() as u32).to_le_bytes())?;
+        file.write_all(&encoded)?;
+    }
+
+    file.write_all(&sim_state.time.to_le_bytes())?;
+    file.write_all(&sim_state.r_valve.to_le_bytes())?;
+    file.write_all(&sim_state.c_valve.to_le_bytes())?;
+    file.write_all(&sim_state.i_valve.to_le_bytes())?;
+    file.write_all(&sim_state.total_pop.to_le_bytes())?;
+    file.write_all(&sim_state.num_jobs.to_le_bytes())?;
+    file.write_all(&sim_state.growth_rate.to_le_bytes())?;
+    file.write_all(&sim_state.pollution.to_le_bytes())?;
+    file.write_all(&sim_state.goods_produced.to_le_bytes())?;
+    file.write_all(&sim_state.goods_delivered.to_le_bytes())?;
+
+    Ok(())
+}
+
+/// Reads a save file written by `save_city`, rejecting anything whose magic bytes or format
+/// version don't match rather than risk misinterpreting a stream laid out differently.
+pub fn load_city(path: impl AsRef<Path>) -> Result<(GameMap, SimulationState), PersistenceError> {
+    let mut file = File::open(path)?;
+
+    let mut magic = [0u8; 4];
+    file.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(PersistenceError::BadMagic);
+    }
+
+    let version = read_u32(&mut file)?;
+    if version != FORMAT_VERSION {
+        return Err(PersistenceError::UnsupportedVersion(version));
+    }
+
+    let width = read_u32(&mut file)?;
+    let height = read_u32(&mut file)?;
+    let layer_count = read_u32(&mut file)?;
+
+    let mut layers = HashMap::default();
+    for _ in 0..layer_count {
+        let layer_id = read_i32(&mut file)?;
+        let encoded_len = read_u32(&mut file)? as usize;
+        let mut encoded = vec![0u8; encoded_len];
+        file.read_exact(&mut encoded)?;
+        let tiles = rle_decode(&encoded, (width * height) as usize)?;
+        layers.insert(layer_id, tiles);
+    }
+
+    let sim_state = SimulationState {
+        time: read_u64(&mut file)?,
+        r_valve: read_i16(&mut file)?,
+        c_valve: read_i16(&mut file)?,
+        i_valve: read_i16(&mut file)?,
+        total_pop: read_u32(&mut file)?,
+        num_jobs: read_u32(&mut file)?,
+        growth_rate: read_f32(&mut file)?,
+        pollution: read_u32(&mut file)?,
+        goods_produced: read_u32(&mut file)?,
+        goods_delivered: read_u32(&mut file)?,
+    };
+
+    Ok((GameMap { width, height, layers }, sim_state))
+}
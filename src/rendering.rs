@@ -1,10 +1,14 @@
 use crate::{map::TileType, GameMap, GameState};
 use bevy::{
+    core_pipeline::{bloom::BloomSettings, tonemapping::Tonemapping, Skybox},
     input::mouse::{MouseMotion, MouseWheel},
+    pbr::{CascadeShadowConfigBuilder, DirectionalLightShadowMap},
     prelude::*,
     render::{
+        camera::{ScalingMode, Viewport},
         mesh::{Indices, PrimitiveTopology, VertexAttributeValues},
         render_asset::RenderAssetUsages,
+        view::RenderLayers,
     },
 };
 
@@ -21,20 +25,41 @@ impl Plugin for RenderingPlugin {
         app.init_resource::<ViewMode>()
             .init_resource::<CursorMapPosition>()
             .init_resource::<GridState>()
+            .init_resource::<LayerTransparency>()
+            .init_resource::<BloomToggle>()
+            .init_resource::<TimeOfDay>()
+            .insert_resource(DirectionalLightShadowMap { size: 4096 })
             .add_event::<ChunkUpdateEvent>()
-            .add_systems(Startup, setup_camera)
-            .add_systems(OnEnter(GameState::Game), spawn_all_chunks)
+            .add_systems(Startup, (setup_camera, load_skybox))
+            .add_systems(
+                Update,
+                (apply_skybox_once_loaded, apply_bloom_toggle, update_time_of_day),
+            )
+            .add_systems(
+                OnEnter(GameState::Game),
+                (spawn_all_chunks, spawn_minimap_camera),
+            )
             .add_systems(
                 Update,
                 (
                     camera_controller,
-                    update_layer_visibility,
                     grid_visibility_system,
                     raycast_system,
                     draw_cursor_gizmo,
-                    update_chunks,
+                    update_minimap_marker,
                 )
                     .run_if(in_state(GameState::Game)),
+            )
+            .add_systems(
+                Update,
+                // `update_chunks` spawns rebuilt chunk meshes via deferred `Commands`, which
+                // aren't visible to queries until flushed. Chain an explicit `apply_deferred`
+                // before `apply_layer_transparency` so a chunk rebuilt this frame is faded
+                // immediately instead of rendering at full `BaseAlpha` until the next
+                // view/transparency toggle.
+                (update_chunks, bevy::ecs::schedule::apply_deferred, apply_layer_transparency)
+                    .chain()
+                    .run_if(in_state(GameState::Game)),
             );
     }
 }
@@ -50,11 +75,117 @@ pub enum ViewMode {
     #[default]
     Surface,
     Underground,
+    Air,
+}
+
+/// The map layer (`MapLayer` value) a `ViewMode` edits/focuses.
+fn view_mode_layer(mode: ViewMode) -> i32 {
+    match mode {
+        ViewMode::Underground => -1,
+        ViewMode::Surface => 0,
+        ViewMode::Air => 1,
+    }
+}
+
+/// Index into `LayerTransparency`'s arrays for a given `MapLayer` value.
+fn layer_slot(layer: i32) -> usize {
+    match layer {
+        -1 => 0,
+        1 => 2,
+        _ => 1, // Surface (0), and anything unexpected, falls back to the surface slot.
+    }
 }
 
 #[derive(Component)]
 pub struct MapLayer(pub i32);
 
+/// A mesh's designed, un-faded `base_color` alpha (e.g. 0.5 for zone plates, 0.8 for power
+/// lines, 1.0 for everything solid), recorded at spawn time so `apply_layer_transparency` can
+/// restore it exactly when the layer isn't faded instead of forcing full opacity.
+#[derive(Component)]
+pub struct BaseAlpha(pub f32);
+
+/// Per-layer alpha used when a layer isn't the active `ViewMode` layer, and whether that fading
+/// is currently switched on (OpenTTD-style transparency toggles). Slots are `-1`/`0`/`1` mapped
+/// to `0`/`1`/`2` via `layer_slot`. Defaulting every slot to transparent gives the "X-ray" effect
+/// of seeing the surface faintly while editing underground pipes/subways for free, while still
+/// letting the player flip a layer back to fully opaque to inspect it in isolation.
+#[derive(Resource, Clone, Copy)]
+pub struct LayerTransparency {
+    pub layer_alpha: [f32; 3],
+    pub transparent: [bool; 3],
+}
+
+impl Default for LayerTransparency {
+    fn default() -> Self {
+        Self {
+            layer_alpha: [0.15, 0.15, 0.15],
+            transparent: [true, true, true],
+        }
+    }
+}
+
+/// The single camera `camera_controller` drives and everything else (raycasting, bloom, skybox)
+/// assumes is unique. Distinguishes it from `MinimapCamera` now that the app renders two.
+#[derive(Component)]
+pub struct MainCamera;
+
+/// The orthographic, top-down camera rendering the picture-in-picture minimap.
+#[derive(Component)]
+struct MinimapCamera;
+
+/// Everything the minimap should see but the main view shouldn't — currently just
+/// `MinimapMarker`. Layer 0 (the default, unset `RenderLayers`) holds the city itself and is
+/// shared by both cameras.
+const MINIMAP_OVERLAY_LAYER: usize = 1;
+
+/// Which on-screen viewport a camera renders into. `viewport_specs` is the single source of
+/// truth `setup_camera`/`spawn_minimap_camera` read instead of each hard-coding its own camera,
+/// so a future picture-in-picture view (crime map, pollution overlay) is one more entry.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ViewportRole {
+    Main,
+    Minimap,
+}
+
+struct ViewportSpec {
+    role: ViewportRole,
+    /// Normalized top-left corner and size within the primary window, each axis 0.0..1.0.
+    rect: (Vec2, Vec2),
+    order: isize,
+}
+
+fn viewport_specs() -> Vec<ViewportSpec> {
+    vec![
+        ViewportSpec {
+            role: ViewportRole::Main,
+            rect: (Vec2::ZERO, Vec2::ONE),
+            order: 0,
+        },
+        ViewportSpec {
+            role: ViewportRole::Minimap,
+            rect: (Vec2::new(0.76, 0.02), Vec2::new(0.22, 0.22)),
+            order: 1,
+        },
+    ]
+}
+
+fn viewport_spec(role: ViewportRole) -> ViewportSpec {
+    viewport_specs()
+        .into_iter()
+        .find(|v| v.role == role)
+        .expect("viewport_specs must cover every ViewportRole")
+}
+
+fn physical_viewport(spec: &ViewportSpec, window: &Window) -> Viewport {
+    let window_size = window.physical_size().as_vec2();
+    Viewport {
+        physical_position: (spec.rect.0 * window_size).as_uvec2(),
+        physical_size: (spec.rect.1 * window_size).as_uvec2().max(UVec2::ONE),
+        ..default()
+    }
+}
+
 #[derive(Component)]
 pub struct CameraController {
     pub scroll_speed: f32,
@@ -302,6 +433,7 @@ fn spawn_all_chunks(
                     (map.height as f32) / 2.0 - 0.5,
                 ),
                 MapLayer(-1),
+                BaseAlpha(1.0),
             ));
 
             // Grid Plane (Layer 0, slightly above)
@@ -338,12 +470,17 @@ fn update_chunks(
     map: Res<GameMap>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    power_grid: Res<crate::simulation::PowerGrid>,
     root_query: Query<Entity, With<MapRoot>>,
     chunk_query: Query<(Entity, &ChunkCoord)>,
 ) {
     let dirt_mat = materials.add(Color::srgb(0.4, 0.3, 0.2));
+    let rubble_mat = materials.add(Color::srgb(0.25, 0.22, 0.2));
     let water_mat = materials.add(Color::srgb(0.2, 0.4, 0.8));
     let road_mat = materials.add(Color::srgb(0.2, 0.2, 0.2));
+    let avenue_mat = materials.add(Color::srgb(0.35, 0.28, 0.12));
+    let highway_mat = materials.add(Color::srgb(0.12, 0.12, 0.16));
+    let one_way_mat = materials.add(Color::srgb(0.18, 0.32, 0.18));
 
     // Transparent Base Materials
     let res_mat = materials.add(StandardMaterial {
@@ -362,11 +499,53 @@ fn update_chunks(
         ..default()
     });
 
-    // Solid Building Materials
-    let res_build_mat = materials.add(Color::srgb(0.4, 1.0, 0.4));
-    let com_build_mat = materials.add(Color::srgb(0.4, 0.4, 1.0));
-    let ind_build_mat = materials.add(Color::srgb(1.0, 1.0, 0.4));
-    let power_plant_mat = materials.add(Color::srgb(1.0, 0.2, 0.2)); // Red Plant
+    // Solid Building Materials. Emissive is scaled by the grid's city-wide `lit_fraction` so
+    // occupied buildings visibly glow/bloom when the district is powered and go dark in a
+    // brownout — a coarse approximation since every tile of a given zone type still shares one
+    // material rather than one per tile.
+    let glow = power_grid.lit_fraction;
+    let res_build_mat = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.4, 1.0, 0.4),
+        emissive: (Color::srgb(0.4, 1.0, 0.4).to_linear() * glow * 0.6),
+        ..default()
+    });
+    let com_build_mat = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.4, 0.4, 1.0),
+        emissive: (Color::srgb(0.4, 0.4, 1.0).to_linear() * glow * 0.6),
+        ..default()
+    });
+    let ind_build_mat = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 1.0, 0.4),
+        emissive: (Color::srgb(1.0, 1.0, 0.4).to_linear() * glow * 0.6),
+        ..default()
+    });
+
+    // One material per plant kind so each glows proportionally to its own generation capacity
+    // (nuclear brightest, wind/solar barely lit), the same "typed plant" split used for supply
+    // and pollution.
+    let power_plant_mats: std::collections::HashMap<TileType, Handle<StandardMaterial>> = [
+        TileType::PowerPlantCoal,
+        TileType::PowerPlantGas,
+        TileType::PowerPlantNuclear,
+        TileType::PowerPlantSolar,
+        TileType::PowerPlantWind,
+    ]
+    .into_iter()
+    .map(|kind| {
+        let supply = crate::simulation::plant_supply(kind) as f32;
+        let glow = (supply / 500.0).clamp(0.3, 5.0);
+        let mat = materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.2, 0.2),
+            emissive: Color::srgb(1.0, 0.35, 0.05).to_linear() * glow,
+            ..default()
+        });
+        (kind, mat)
+    })
+    .collect();
+
+    // Underground Network Materials
+    let subway_mat = materials.add(Color::srgb(0.5, 0.5, 0.55));
+    let pipe_mat = materials.add(Color::srgb(0.25, 0.45, 0.8));
 
     // Power Line (Yellow-ish, Unlit)
     let power_line_mat = materials.add(StandardMaterial {
@@ -403,12 +582,17 @@ fn update_chunks(
     commands.entity(root_entity).with_children(|parent| {
         let surface_tiles_opt = map.layers.get(&0);
         let air_tiles_opt = map.layers.get(&1);
+        let underground_tiles_opt = map.layers.get(&-1);
 
         if let Some(surface_tiles) = surface_tiles_opt {
             for (cx, cz) in dirty_chunks {
                 let mut dirt_builder = MeshBuilder::new();
+                let mut rubble_builder = MeshBuilder::new();
                 let mut water_builder = MeshBuilder::new();
                 let mut road_builder = MeshBuilder::new();
+                let mut avenue_builder = MeshBuilder::new();
+                let mut highway_builder = MeshBuilder::new();
+                let mut one_way_builder = MeshBuilder::new();
 
                 let mut res_builder = MeshBuilder::new();
                 let mut com_builder = MeshBuilder::new();
@@ -418,9 +602,13 @@ fn update_chunks(
                 let mut com_build_builder = MeshBuilder::new();
                 let mut ind_build_builder = MeshBuilder::new();
 
-                let mut power_plant_builder = MeshBuilder::new();
+                let mut power_plant_builders: std::collections::HashMap<TileType, MeshBuilder> =
+                    power_plant_mats.keys().map(|&k| (k, MeshBuilder::new())).collect();
                 let mut power_line_builder = MeshBuilder::new();
 
+                let mut subway_builder = MeshBuilder::new();
+                let mut pipe_builder = MeshBuilder::new();
+
                 let start_x = cx * CHUNK_SIZE;
                 let start_z = cz * CHUNK_SIZE;
                 let end_x = (start_x + CHUNK_SIZE).min(map.width);
@@ -438,7 +626,13 @@ fn update_chunks(
                     match surface_tiles[idx] {
                         TileType::Empty => -100.0,
                         TileType::Water => 0.6,
-                        TileType::Road => 1.05,
+                        TileType::Road
+                        | TileType::Avenue
+                        | TileType::Highway
+                        | TileType::RoadOneWayNorth
+                        | TileType::RoadOneWayEast
+                        | TileType::RoadOneWaySouth
+                        | TileType::RoadOneWayWest => 1.05,
                         _ => 1.0,
                     }
                 };
@@ -469,6 +663,28 @@ fn update_chunks(
                                     TileType::Road => {
                                         road_builder.add_block(fx, 0.0, fz, 1.0, 1.05, sides)
                                     }
+                                    TileType::Avenue => {
+                                        avenue_builder.add_block(fx, 0.0, fz, 1.0, 1.05, sides)
+                                    }
+                                    // Highways are rendered spanning beyond their own tile
+                                    // (wider footprint), matching their higher road class.
+                                    TileType::Highway => highway_builder.add_block(
+                                        fx - 0.15,
+                                        0.0,
+                                        fz - 0.15,
+                                        1.3,
+                                        1.15,
+                                        sides,
+                                    ),
+                                    TileType::RoadOneWayNorth
+                                    | TileType::RoadOneWayEast
+                                    | TileType::RoadOneWaySouth
+                                    | TileType::RoadOneWayWest => {
+                                        one_way_builder.add_block(fx, 0.0, fz, 1.0, 1.05, sides)
+                                    }
+                                    TileType::Rubble => {
+                                        rubble_builder.add_block(fx, 0.0, fz, 1.0, 1.0, sides)
+                                    }
                                     TileType::Residential => {
                                         res_builder.add_block(fx, 0.0, fz, 1.0, 1.0, sides)
                                     }
@@ -559,18 +775,24 @@ fn update_chunks(
                                         ind_build_builder
                                             .add_block(fx, 1.0, fz, 1.0, 3.0, [true; 4]);
                                     }
-                                    TileType::PowerPlant => {
+                                    TileType::PowerPlantCoal
+                                    | TileType::PowerPlantGas
+                                    | TileType::PowerPlantNuclear
+                                    | TileType::PowerPlantSolar
+                                    | TileType::PowerPlantWind => {
                                         // Foundation
                                         ind_builder.add_block(fx, 0.0, fz, 1.0, 1.0, sides);
-                                        // Plant Block
-                                        power_plant_builder.add_block(
-                                            fx + 0.1,
-                                            1.0,
-                                            fz + 0.1,
-                                            0.8,
-                                            0.8,
-                                            [true; 4],
-                                        );
+                                        // Plant Block, routed to the builder for this plant's kind
+                                        if let Some(builder) = power_plant_builders.get_mut(tile) {
+                                            builder.add_block(
+                                                fx + 0.1,
+                                                1.0,
+                                                fz + 0.1,
+                                                0.8,
+                                                0.8,
+                                                [true; 4],
+                                            );
+                                        }
                                     }
                                     _ => dirt_builder.add_block(fx, 0.0, fz, 1.0, 1.0, sides),
                                 }
@@ -640,6 +862,22 @@ fn update_chunks(
                                 }
                             }
                         }
+
+                        // --- Layer -1: Underground (Subway / Pipes) ---
+                        // Trench-style: a block sunk below Y=0, distinct depth/material per network.
+                        if let Some(underground_tiles) = underground_tiles_opt {
+                            if let Some(tile) = underground_tiles.get(idx) {
+                                match *tile {
+                                    TileType::Subway => {
+                                        subway_builder.add_block(fx, -1.2, fz, 1.0, 0.7, [true; 4]);
+                                    }
+                                    TileType::Pipe => {
+                                        pipe_builder.add_block(fx, -0.5, fz, 1.0, 0.3, [true; 4]);
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
                     }
                 }
 
@@ -649,11 +887,20 @@ fn update_chunks(
                                       name: &str,
                                       layer: i32| {
                     if !builder.positions.is_empty() {
+                        // Remember the material's own designed alpha (zone plates at 0.5, the
+                        // power-line material at 0.8, everything else opaque at 1.0) so
+                        // `apply_layer_transparency` can restore it instead of clobbering it
+                        // with a hard-coded 1.0 once the layer is no longer faded.
+                        let base_alpha = materials
+                            .get(&mat)
+                            .map(|m| m.base_color.alpha())
+                            .unwrap_or(1.0);
                         parent.spawn((
                             Mesh3d(meshes.add(builder.build())),
                             MeshMaterial3d(mat),
                             Transform::default(),
                             MapLayer(layer),
+                            BaseAlpha(base_alpha),
                             ChunkCoord { x: cx, z: cz },
                             Name::new(format!("{}_{}_{}", name, cx, cz)),
                         ));
@@ -661,8 +908,17 @@ fn update_chunks(
                 };
 
                 spawn_mesh(dirt_builder, dirt_mat.clone(), "Chunk_Dirt", 0);
+                spawn_mesh(rubble_builder, rubble_mat.clone(), "Chunk_Rubble", 0);
                 spawn_mesh(water_builder, water_mat.clone(), "Chunk_Water", 0);
                 spawn_mesh(road_builder, road_mat.clone(), "Chunk_Road", 0);
+                spawn_mesh(avenue_builder, avenue_mat.clone(), "Chunk_Avenue", 0);
+                spawn_mesh(highway_builder, highway_mat.clone(), "Chunk_Highway", 0);
+                spawn_mesh(
+                    one_way_builder,
+                    one_way_mat.clone(),
+                    "Chunk_RoadOneWay",
+                    0,
+                );
                 spawn_mesh(res_builder, res_mat.clone(), "Chunk_Res", 0);
                 spawn_mesh(com_builder, com_mat.clone(), "Chunk_Com", 0);
                 spawn_mesh(ind_builder, ind_mat.clone(), "Chunk_Ind", 0);
@@ -685,12 +941,11 @@ fn update_chunks(
                     "Chunk_Ind_Build",
                     0,
                 );
-                spawn_mesh(
-                    power_plant_builder,
-                    power_plant_mat.clone(),
-                    "Chunk_PowerPlant",
-                    0,
-                );
+                for (kind, builder) in power_plant_builders {
+                    if let Some(mat) = power_plant_mats.get(&kind) {
+                        spawn_mesh(builder, mat.clone(), "Chunk_PowerPlant", 0);
+                    }
+                }
 
                 spawn_mesh(
                     power_line_builder,
@@ -698,6 +953,9 @@ fn update_chunks(
                     "Chunk_PowerLine",
                     1,
                 ); // Layer 1
+
+                spawn_mesh(subway_builder, subway_mat.clone(), "Chunk_Subway", -1);
+                spawn_mesh(pipe_builder, pipe_mat.clone(), "Chunk_Pipe", -1);
             }
         }
     });
@@ -706,27 +964,271 @@ fn update_chunks(
 fn setup_camera(mut commands: Commands) {
     commands.spawn((
         Camera3d::default(),
+        Camera {
+            hdr: true,
+            order: viewport_spec(ViewportRole::Main).order,
+            ..default()
+        },
+        Tonemapping::TonyMcMapface,
+        BloomSettings::default(),
         Transform::default(),
         CameraController::default(),
+        MainCamera,
     ));
 
+    let cascade_shadow_config = CascadeShadowConfigBuilder {
+        num_cascades: 4,
+        minimum_distance: 1.0,
+        maximum_distance: 200.0, // Tuned to a few chunks' worth of world-space tiles.
+        first_cascade_far_bound: 20.0,
+        overlap_proportion: 0.3,
+    }
+    .build();
+
     commands.spawn((
-        DirectionalLight::default(),
+        DirectionalLight {
+            shadows_enabled: true,
+            ..default()
+        },
         Transform::from_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_4)),
+        cascade_shadow_config,
+        Sun,
+    ));
+}
+
+/// Marks the single `DirectionalLight` that `update_time_of_day` drives as the sun.
+#[derive(Component)]
+struct Sun;
+
+/// Small flat marker, visible only to the minimap, tracking where the main camera is looking.
+#[derive(Component)]
+struct MinimapMarker;
+
+/// Spawns the top-down orthographic minimap camera and its follow marker once `GameMap` has its
+/// real dimensions (on entering `GameState::Game`, after the menu picks a map size).
+fn spawn_minimap_camera(
+    mut commands: Commands,
+    map: Res<GameMap>,
+    window_query: Query<&Window>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+
+    let spec = viewport_spec(ViewportRole::Minimap);
+    let half_extent = map.width.max(map.height) as f32 * 0.55;
+    let center = Vec3::new(map.width as f32 / 2.0, 0.0, map.height as f32 / 2.0);
+
+    commands.spawn((
+        Camera3d::default(),
+        Camera {
+            order: spec.order,
+            viewport: Some(physical_viewport(&spec, window)),
+            ..default()
+        },
+        Projection::Orthographic(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical(half_extent * 2.0),
+            ..OrthographicProjection::default_3d()
+        }),
+        Transform::from_translation(center + Vec3::Y * (half_extent * 2.0))
+            .looking_at(center, Vec3::NEG_Z),
+        RenderLayers::from_layers(&[0, MINIMAP_OVERLAY_LAYER]),
+        MinimapCamera,
     ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::new(Vec3::Y, Vec2::splat(1.5)))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.2, 0.2),
+            unlit: true,
+            ..default()
+        })),
+        Transform::from_translation(center + Vec3::Y * 0.5),
+        RenderLayers::layer(MINIMAP_OVERLAY_LAYER),
+        MinimapMarker,
+    ));
+}
+
+/// Keeps the minimap marker over whatever point the main camera is orbiting.
+fn update_minimap_marker(
+    main_camera: Query<&CameraController, With<MainCamera>>,
+    mut marker: Query<&mut Transform, With<MinimapMarker>>,
+) {
+    let Ok(controller) = main_camera.get_single() else {
+        return;
+    };
+    let Ok(mut transform) = marker.get_single_mut() else {
+        return;
+    };
+    transform.translation = controller.target + Vec3::Y * 0.5;
+}
+
+/// Current in-game hour (0.0..24.0) driving the sun's arc. Advances from real `Time` at
+/// `hours_per_second`, independent of the simulation's own tick clock so the sky keeps moving
+/// even while paused.
+#[derive(Resource)]
+pub struct TimeOfDay {
+    pub hour: f32,
+    pub hours_per_second: f32,
+}
+
+impl Default for TimeOfDay {
+    fn default() -> Self {
+        Self {
+            hour: 8.0, // Start in mid-morning so the city isn't dark on launch.
+            hours_per_second: 0.25,
+        }
+    }
+}
+
+fn update_time_of_day(
+    time: Res<Time>,
+    mut clock: ResMut<TimeOfDay>,
+    mut sun_query: Query<(&mut Transform, &mut DirectionalLight), With<Sun>>,
+) {
+    clock.hour = (clock.hour + clock.hours_per_second * time.delta_secs()) % 24.0;
+
+    let Ok((mut transform, mut light)) = sun_query.get_single_mut() else {
+        return;
+    };
+
+    // Map the 24h clock onto a full sun arc: noon directly overhead, midnight directly below.
+    // Phase the arc off noon (hour 6 is the "horizon" crossing) so it agrees with `altitude` below.
+    let angle = ((clock.hour - 6.0) / 24.0) * std::f32::consts::TAU;
+    transform.rotation = Quat::from_rotation_x(-angle);
+
+    // Altitude of the sun above the horizon, -1.0 (midnight) to 1.0 (noon).
+    let altitude = angle.sin();
+
+    if altitude <= 0.0 {
+        // Sun below the horizon: dim ambient-only moonlight.
+        light.color = Color::srgb(0.4, 0.45, 0.6);
+        light.illuminance = 50.0;
+    } else if altitude < 0.2 {
+        // Low sun: warm dawn/dusk light, fading in/out near the horizon.
+        light.color = Color::srgb(1.0, 0.6, 0.35);
+        light.illuminance = 50.0 + (altitude / 0.2) * 9000.0;
+    } else {
+        // Full daylight, brightest and whitest at noon.
+        let noon_factor = ((altitude - 0.2) / 0.8).clamp(0.0, 1.0);
+        light.color = Color::srgb(1.0, 0.95 + noon_factor * 0.05, 0.85 + noon_factor * 0.15);
+        light.illuminance = 9000.0 + noon_factor * 3000.0;
+    }
+}
+
+/// Whether the camera's `BloomSettings` should be attached. Exposed so low-end machines can
+/// disable bloom without losing the rest of the HDR pipeline (tonemapping stays on regardless).
+#[derive(Resource)]
+pub struct BloomToggle(pub bool);
+
+impl Default for BloomToggle {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+fn apply_bloom_toggle(
+    mut commands: Commands,
+    toggle: Res<BloomToggle>,
+    camera_query: Query<(Entity, Option<&BloomSettings>), With<MainCamera>>,
+) {
+    if !toggle.is_changed() {
+        return;
+    }
+    if let Ok((entity, existing)) = camera_query.get_single() {
+        if toggle.0 && existing.is_none() {
+            commands.entity(entity).insert(BloomSettings::default());
+        } else if !toggle.0 && existing.is_some() {
+            commands.entity(entity).remove::<BloomSettings>();
+        }
+    }
+}
+
+/// The cubemap backing the camera's `Skybox`, plus whether it has already been reinterpreted as
+/// a cube texture view. The image is loaded once at startup; `apply_skybox_once_loaded` attaches
+/// the `Skybox` component only after the asset finishes loading and only does so once.
+#[derive(Resource)]
+struct SkyboxAssets {
+    handle: Handle<Image>,
+    is_loaded: bool,
+}
+
+fn load_skybox(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(SkyboxAssets {
+        handle: asset_server.load("textures/skybox.png"),
+        is_loaded: false,
+    });
 }
 
+fn apply_skybox_once_loaded(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut skybox: ResMut<SkyboxAssets>,
+    camera_query: Query<Entity, With<MainCamera>>,
+) {
+    if skybox.is_loaded {
+        return;
+    }
+    if asset_server.load_state(&skybox.handle) != bevy::asset::LoadState::Loaded {
+        return;
+    }
+
+    if let Some(image) = images.get_mut(&skybox.handle) {
+        // The source file is a vertical strip of 6 square faces; reinterpret it as a cube array
+        // so it can be sampled as a `Skybox`.
+        image.reinterpret_stacked_2d_as_array(6);
+        image.texture_view_descriptor = Some(bevy::render::render_resource::TextureViewDescriptor {
+            dimension: Some(bevy::render::render_resource::TextureViewDimension::Cube),
+            ..default()
+        });
+    } else {
+        return;
+    }
+
+    if let Ok(camera) = camera_query.get_single() {
+        commands.entity(camera).insert(Skybox {
+            image: skybox.handle.clone(),
+            brightness: 1000.0,
+            ..default()
+        });
+    }
+
+    skybox.is_loaded = true;
+}
+
+/// Pitch is stored negative (see `CameraController::default`); the more negative, the closer to
+/// looking straight down. These bounds keep it between a near-horizon 15° and a near-top-down 85°
+/// so the camera can never orbit under the terrain.
+const MIN_PITCH: f32 = -85.0 * std::f32::consts::PI / 180.0;
+const MAX_PITCH: f32 = -15.0 * std::f32::consts::PI / 180.0;
+
+/// Pixels from a window edge that count as "hovering the edge" for edge-scroll panning.
+const EDGE_SCROLL_MARGIN: f32 = 16.0;
+
+/// Panning speed scales with `distance` so zoomed-out orbits cover ground at the same apparent
+/// rate as zoomed-in ones; this is the reference distance the un-scaled `scroll_speed` is tuned
+/// for (matches `CameraController::default`).
+const REFERENCE_DISTANCE: f32 = 50.0;
+
 fn camera_controller(
     time: Res<Time>,
     mut mouse_motion_events: EventReader<MouseMotion>,
     mut mouse_wheel_events: EventReader<MouseWheel>,
     mouse_button_input: Res<ButtonInput<MouseButton>>,
     key_input: Res<ButtonInput<KeyCode>>,
+    window_query: Query<&Window>,
+    map: Res<GameMap>,
     mut query: Query<(&mut Transform, &mut CameraController)>,
 ) {
     let dt = time.delta_secs();
+    let window = window_query.get_single().ok();
 
     for (mut transform, mut controller) in query.iter_mut() {
+        let pan_speed = controller.scroll_speed * (controller.distance / REFERENCE_DISTANCE).max(0.25);
+
         // --- 1. Keyboard Panning (WASD / Arrows) ---
         let mut panning = Vec3::ZERO;
         let forward = Vec3::new(controller.yaw.sin(), 0.0, controller.yaw.cos());
@@ -745,12 +1247,31 @@ fn camera_controller(
             panning += right;
         }
 
+        // --- 2. Screen-Edge Scrolling ---
+        if let Some(window) = window {
+            if let Some(cursor) = window.cursor_position() {
+                if cursor.x <= EDGE_SCROLL_MARGIN {
+                    panning -= right;
+                } else if cursor.x >= window.width() - EDGE_SCROLL_MARGIN {
+                    panning += right;
+                }
+                if cursor.y <= EDGE_SCROLL_MARGIN {
+                    panning += forward;
+                } else if cursor.y >= window.height() - EDGE_SCROLL_MARGIN {
+                    panning -= forward;
+                }
+            }
+        }
+
         if panning != Vec3::ZERO {
-            panning = panning.normalize() * controller.scroll_speed * dt;
-            controller.target += panning;
+            controller.target += panning.normalize() * pan_speed * dt;
         }
 
-        // --- 2. Rotation (Q/E) ---
+        // Keep the city in view no matter how far the player pans.
+        controller.target.x = controller.target.x.clamp(0.0, map.width as f32);
+        controller.target.z = controller.target.z.clamp(0.0, map.height as f32);
+
+        // --- 3. Rotation (Q/E) ---
         if key_input.pressed(KeyCode::KeyQ) {
             controller.yaw -= controller.rotate_speed * dt;
         }
@@ -758,19 +1279,23 @@ fn camera_controller(
             controller.yaw += controller.rotate_speed * dt;
         }
 
-        // --- 3. Zoom (Mouse Wheel) ---
+        // --- 4. Zoom (Mouse Wheel) ---
         for event in mouse_wheel_events.read() {
             controller.distance -= event.y * controller.zoom_speed;
             controller.distance = controller.distance.clamp(5.0, 200.0);
         }
 
-        // --- 4. Mouse Pan/Rotate (Optional, Middle Click?) ---
+        // --- 5. Mouse Orbit (Middle Click Drag) ---
+        // Right-drag is reserved by `handle_interaction`/the inspector window for cancel-drag and
+        // the tile inspector, so only middle-click orbits the camera.
         if mouse_button_input.pressed(MouseButton::Middle) {
             for event in mouse_motion_events.read() {
-                // Rotate with Mouse Drag
                 controller.yaw -= event.delta.x * 0.01;
-                // Pitch? (Optional)
+                controller.pitch -= event.delta.y * 0.01;
             }
+            controller.pitch = controller.pitch.clamp(MIN_PITCH, MAX_PITCH);
+        } else {
+            mouse_motion_events.clear();
         }
 
         // Update Transform
@@ -781,33 +1306,55 @@ fn camera_controller(
     }
 }
 
-fn update_layer_visibility(
+/// Composites every map layer that isn't the active `ViewMode` layer at its configured alpha
+/// instead of hiding it outright (OpenTTD-style transparency toggles), so e.g. editing power
+/// lines on the air layer still shows the roads and zones beneath. `T` toggles the underground
+/// and air layers' transparency together as a quick all-or-nothing switch; `X` already selects
+/// the Gas power plant tool (see `tool_hotkey_system` in ui.rs), so it wasn't free to reuse here.
+fn apply_layer_transparency(
+    keys: Res<ButtonInput<KeyCode>>,
     view_mode: Res<ViewMode>,
-    mut query: Query<(&mut Visibility, &MapLayer)>,
+    mut transparency: ResMut<LayerTransparency>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut chunk_events: EventReader<ChunkUpdateEvent>,
+    query: Query<(&MeshMaterial3d<StandardMaterial>, &MapLayer, &BaseAlpha)>,
 ) {
-    // Note: GridPlane has MapLayer(0) assigned in spawn_all_chunks
-    // So it will be handled by the main query loop below.
-
-    // Logic:
-    // ViewMode::Surface: Show Layer 0, Hide Layer -1 (Underground)
-    // ViewMode::Underground: Hide Layer 0, Show Layer -1
+    if keys.just_pressed(KeyCode::KeyT) {
+        transparency.transparent[layer_slot(-1)] = !transparency.transparent[layer_slot(-1)];
+        transparency.transparent[layer_slot(1)] = !transparency.transparent[layer_slot(1)];
+    }
 
-    let target_layer = match *view_mode {
-        ViewMode::Surface => 0,
-        ViewMode::Underground => -1,
-    };
+    // `update_chunks` rebuilds dirty chunks with brand-new material handles at their designed,
+    // un-faded alpha every time a tile changes, so rebuilt chunks must also re-run this even when
+    // neither `view_mode` nor `transparency` themselves changed this frame.
+    let chunks_rebuilt = chunk_events.read().count() > 0;
+    if !view_mode.is_changed() && !transparency.is_changed() && !chunks_rebuilt {
+        return;
+    }
 
-    for (mut vis, layer) in query.iter_mut() {
-        if layer.0 == target_layer {
-            *vis = Visibility::Inherited;
+    let active_layer = view_mode_layer(*view_mode);
+    for (mat_handle, layer, base_alpha) in query.iter() {
+        let slot = layer_slot(layer.0);
+        let faded = layer.0 != active_layer && transparency.transparent[slot];
+        let alpha = if faded {
+            transparency.layer_alpha[slot]
         } else {
-            *vis = Visibility::Hidden;
+            base_alpha.0
+        };
+        if let Some(mat) = materials.get_mut(&mat_handle.0) {
+            let srgba = mat.base_color.to_srgba();
+            mat.base_color = Color::srgba(srgba.red, srgba.green, srgba.blue, alpha);
+            mat.alpha_mode = if faded || base_alpha.0 < 1.0 {
+                AlphaMode::Blend
+            } else {
+                AlphaMode::Opaque
+            };
         }
     }
 }
 
 fn raycast_system(
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     window_query: Query<&Window>,
     map: Res<GameMap>,
     mut cursor_pos: ResMut<CursorMapPosition>,
@@ -840,13 +1387,32 @@ fn raycast_system(
     cursor_pos.z = None;
 }
 
-fn draw_cursor_gizmo(cursor_pos: Res<CursorMapPosition>, mut gizmos: Gizmos) {
+/// Highlights every tile the brush would stamp if the player clicked right now, each tinted by
+/// whether `tool` is legal there — so the affected area is visible before committing.
+fn draw_cursor_gizmo(
+    cursor_pos: Res<CursorMapPosition>,
+    tool: Res<crate::ui::ToolState>,
+    brush_size: Res<crate::ui::BrushSize>,
+    map: Res<GameMap>,
+    mut gizmos: Gizmos,
+) {
     if let (Some(x), Some(z)) = (cursor_pos.x, cursor_pos.z) {
-        gizmos.cuboid(
-            Transform::from_xyz(x as f32 + 0.5, 0.5, z as f32 + 0.5)
-                .with_scale(Vec3::new(1.0, 1.1, 1.0)),
-            Color::srgba(1.0, 1.0, 0.0, 0.5),
-        );
+        for (ox, oz) in crate::brush_offsets(brush_size.0) {
+            let (bx, bz) = (x + ox, z + oz);
+            if bx < 0 || bx >= map.width as i32 || bz < 0 || bz >= map.height as i32 {
+                continue;
+            }
+            let color = if crate::tool_placement_valid(&map, *tool, bx, bz) {
+                Color::srgba(0.0, 1.0, 0.0, 0.5)
+            } else {
+                Color::srgba(1.0, 0.0, 0.0, 0.5)
+            };
+            gizmos.cuboid(
+                Transform::from_xyz(bx as f32 + 0.5, 0.5, bz as f32 + 0.5)
+                    .with_scale(Vec3::new(1.0, 1.1, 1.0)),
+                color,
+            );
+        }
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::traffic::{density_at, TrafficMap};
 use crate::{map::TileType, rendering::ChunkUpdateEvent, GameMap, GameState};
 use bevy::prelude::*;
 use rand::Rng;
@@ -11,12 +12,65 @@ pub struct SimulationState {
     pub total_pop: u32,
     pub num_jobs: u32,
     pub growth_rate: f32,
+    pub pollution: u32, // Summed pollution output of all plants on the map
+    pub goods_produced: u32, // Goods industry produced this census
+    pub goods_delivered: u32, // Goods that actually had a road route to a commercial tile
 }
 
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct PowerGrid {
     pub powered_tiles: std::collections::HashSet<(i32, i32)>,
     pub net_power: i32, // Supply - Demand
+    // Fraction of total consumer demand currently served, city-wide. 1.0 when every consumer has
+    // power. Used by rendering as a cheap at-a-glance "is the grid healthy" signal since lit
+    // buildings share one material per zone type rather than one per tile.
+    pub lit_fraction: f32,
+}
+
+impl Default for PowerGrid {
+    fn default() -> Self {
+        Self {
+            powered_tiles: std::collections::HashSet::new(),
+            net_power: 0,
+            lit_fraction: 1.0, // Nothing to starve before the grid has run once.
+        }
+    }
+}
+
+/// Which commercial tiles are currently reachable by road from at least one producing
+/// industrial tile, and which industrial tiles have a road route to at least one commercial
+/// tile. Recomputed every tick by `freight_system` so rendering can later show supplied vs.
+/// starved districts.
+#[derive(Resource, Default)]
+pub struct FreightNetwork {
+    pub reachable_commercial: std::collections::HashSet<(i32, i32)>,
+    pub connected_industrial: std::collections::HashSet<(i32, i32)>,
+}
+
+/// Fired whenever something newsworthy happens to the city (currently: zone abandonment) so a
+/// UI log can surface it to the player, the same way `ChunkUpdateEvent` notifies rendering.
+#[derive(Event, Clone)]
+pub struct CityEvent {
+    pub cause: String,
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Consecutive failed `zone_review_system` checks per occupied tile. Reset to zero (removed) on
+/// a passing review; reaching `ABANDON_THRESHOLD` turns the tile into `Rubble`.
+#[derive(Resource, Default)]
+pub struct AbandonmentTracker {
+    pub strikes: std::collections::HashMap<(i32, i32), u8>,
+}
+
+/// Per-tile pollution and land-value grids, each `width * height` and indexed the same way as
+/// `GameMap`'s surface layer. Updated on a slow cadence by `update_environment_fields` and read
+/// by `update_zones` to bias growth probabilities; also exposed here so a future overlay renderer
+/// can visualize them directly off this resource.
+#[derive(Resource, Default)]
+pub struct EnvironmentFields {
+    pub pollution: Vec<u8>,
+    pub land_value: Vec<u8>,
 }
 
 impl Default for SimulationState {
@@ -29,6 +83,9 @@ impl Default for SimulationState {
             total_pop: 0,
             num_jobs: 0,
             growth_rate: 1.0,
+            pollution: 0,
+            goods_produced: 0,
+            goods_delivered: 0,
         }
     }
 }
@@ -39,14 +96,23 @@ impl Plugin for SimulationPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SimulationState>()
             .init_resource::<PowerGrid>()
+            .init_resource::<PowerNetworkCache>()
+            .init_resource::<FreightNetwork>()
+            .init_resource::<AbandonmentTracker>()
+            .init_resource::<EnvironmentFields>()
+            .add_event::<CityEvent>()
             .add_systems(
                 FixedUpdate,
                 (
                     simulation_tick,
+                    freight_system,
                     census_system,
                     update_valves,
+                    update_environment_fields,
                     update_zones,
+                    mark_power_dirty,
                     update_power_grid,
+                    zone_review_system,
                 )
                     .run_if(in_state(GameState::Game)),
             )
@@ -83,11 +149,101 @@ fn update_valves(mut sim_state: ResMut<SimulationState>) {
         sim_state.c_valve = 0;
         sim_state.i_valve = 0;
     }
+
+    // Industry with no road route to a commercial outlet stalls: the more goods pile up
+    // undelivered, the less pressure there is to grow further.
+    if sim_state.goods_produced > 0 && sim_state.i_valve > 0 {
+        let stalled_ratio = 1.0
+            - (sim_state.goods_delivered as f32 / sim_state.goods_produced as f32).clamp(0.0, 1.0);
+        let penalty = (stalled_ratio * sim_state.i_valve as f32) as i16;
+        sim_state.i_valve -= penalty;
+    }
+}
+
+/// Flood-fills each connected group of road tiles (any class, see `map::is_road`) and cross-references the industrial and
+/// commercial tiles touching it, so commercial growth can require an actual supply route rather
+/// than just valve pressure.
+fn freight_system(map: Res<GameMap>, mut freight: ResMut<FreightNetwork>) {
+    freight.reachable_commercial.clear();
+    freight.connected_industrial.clear();
+
+    let Some(surface) = map.layers.get(&0) else {
+        return;
+    };
+    let width = map.width as i32;
+    let height = map.height as i32;
+
+    let mut visited = std::collections::HashSet::new();
+
+    for (i, &tile) in surface.iter().enumerate() {
+        if !crate::map::is_road(tile) {
+            continue;
+        }
+        let start = ((i as u32 % map.width) as i32, (i as u32 / map.width) as i32);
+        if visited.contains(&start) {
+            continue;
+        }
+
+        // BFS out the connected road component.
+        let mut component = Vec::new();
+        let mut queue = std::collections::VecDeque::new();
+        queue.push_back(start);
+        visited.insert(start);
+        while let Some((cx, cy)) = queue.pop_front() {
+            component.push((cx, cy));
+            for (nx, ny) in [(cx + 1, cy), (cx - 1, cy), (cx, cy + 1), (cx, cy - 1)] {
+                if nx >= 0 && nx < width && ny >= 0 && ny < height && !visited.contains(&(nx, ny)) {
+                    let nidx = (ny as u32 * map.width + nx as u32) as usize;
+                    if crate::map::is_road(surface[nidx]) {
+                        visited.insert((nx, ny));
+                        queue.push_back((nx, ny));
+                    }
+                }
+            }
+        }
+
+        // Gather the industrial producers and commercial tiles touching this component.
+        let mut industrial_neighbors = Vec::new();
+        let mut commercial_neighbors = Vec::new();
+        for &(cx, cy) in &component {
+            for (nx, ny) in [(cx + 1, cy), (cx - 1, cy), (cx, cy + 1), (cx, cy - 1)] {
+                if nx < 0 || nx >= width || ny < 0 || ny >= height {
+                    continue;
+                }
+                let nidx = (ny as u32 * map.width + nx as u32) as usize;
+                match surface[nidx] {
+                    TileType::IndustrialOccupied1
+                    | TileType::IndustrialOccupied2
+                    | TileType::IndustrialOccupied3 => industrial_neighbors.push((nx, ny)),
+                    TileType::Commercial
+                    | TileType::CommercialOccupied1
+                    | TileType::CommercialOccupied2
+                    | TileType::CommercialOccupied3 => commercial_neighbors.push((nx, ny)),
+                    _ => {}
+                }
+            }
+        }
+
+        // Only a component touching both ends of the chain actually moves goods.
+        if !industrial_neighbors.is_empty() && !commercial_neighbors.is_empty() {
+            freight.reachable_commercial.extend(commercial_neighbors);
+            freight.connected_industrial.extend(industrial_neighbors);
+        }
+    }
 }
 
+// Unpowered residential growth is heavily throttled rather than blocked outright.
+const UNPOWERED_GROWTH_PENALTY: f64 = 0.1;
+// Chance per sample that an unpowered commercial/industrial building sheds a level.
+const UNPOWERED_DECAY_CHANCE: f64 = 0.02;
+
 fn update_zones(
     mut map: ResMut<GameMap>,
     sim_state: ResMut<SimulationState>,
+    power_grid: Res<PowerGrid>,
+    freight: Res<FreightNetwork>,
+    fields: Res<EnvironmentFields>,
+    traffic: Res<TrafficMap>,
     mut chunk_events: EventWriter<ChunkUpdateEvent>,
 ) {
     let width = map.width;
@@ -101,6 +257,15 @@ fn update_zones(
         let y = rng.gen_range(0..height);
 
         let idx = (y * width + x) as usize;
+        let powered = power_grid.powered_tiles.contains(&(x as i32, y as i32));
+        let has_freight = freight.reachable_commercial.contains(&(x as i32, y as i32));
+        let pollution = fields.pollution.get(idx).copied().unwrap_or(0);
+        let land_value = fields.land_value.get(idx).copied().unwrap_or(0);
+        let congestion = density_at(&traffic, x as i32, y as i32).min(1.0) as f64;
+        // Heavy local pollution or road congestion chokes growth toward zero; high land value
+        // makes commercial upgrades more likely.
+        let pollution_factor = (1.0 - (pollution as f64 / 255.0)) * (1.0 - congestion);
+        let land_value_factor = 1.0 + (land_value as f64 / 255.0);
 
         if let Some(layers) = map.layers.get_mut(&0) {
             if idx < layers.len() {
@@ -120,16 +285,22 @@ fn update_zones(
                                 y as i32,
                             )
                         {
-                            if rng.gen_bool((0.1 * sim_state.growth_rate as f64).clamp(0.0, 1.0)) {
+                            let chance = 0.1
+                                * sim_state.growth_rate as f64
+                                * if powered { 1.0 } else { UNPOWERED_GROWTH_PENALTY }
+                                * pollution_factor;
+                            if rng.gen_bool(chance.clamp(0.0, 1.0)) {
                                 new_tile = TileType::ResidentialOccupied1;
                                 changed = true;
                             }
                         }
                     }
                     TileType::ResidentialOccupied1 => {
-                        if sim_state.r_valve > 500
-                            && rng.gen_bool((0.05 * sim_state.growth_rate as f64).clamp(0.0, 1.0))
-                        {
+                        let chance = 0.05
+                            * sim_state.growth_rate as f64
+                            * if powered { 1.0 } else { UNPOWERED_GROWTH_PENALTY }
+                            * pollution_factor;
+                        if sim_state.r_valve > 500 && rng.gen_bool(chance.clamp(0.0, 1.0)) {
                             new_tile = TileType::ResidentialOccupied2;
                             changed = true;
                         } else if sim_state.r_valve < -500
@@ -140,9 +311,11 @@ fn update_zones(
                         }
                     }
                     TileType::ResidentialOccupied2 => {
-                        if sim_state.r_valve > 1000
-                            && rng.gen_bool((0.05 * sim_state.growth_rate as f64).clamp(0.0, 1.0))
-                        {
+                        let chance = 0.05
+                            * sim_state.growth_rate as f64
+                            * if powered { 1.0 } else { UNPOWERED_GROWTH_PENALTY }
+                            * pollution_factor;
+                        if sim_state.r_valve > 1000 && rng.gen_bool(chance.clamp(0.0, 1.0)) {
                             new_tile = TileType::ResidentialOccupied3;
                             changed = true;
                         } else if sim_state.r_valve < 0
@@ -179,8 +352,17 @@ fn update_zones(
                         }
                     }
                     TileType::CommercialOccupied1 => {
-                        if sim_state.c_valve > 500
-                            && rng.gen_bool((0.05 * sim_state.growth_rate as f64).clamp(0.0, 1.0))
+                        if !powered {
+                            if rng.gen_bool(UNPOWERED_DECAY_CHANCE) {
+                                new_tile = TileType::Commercial;
+                                changed = true;
+                            }
+                        } else if sim_state.c_valve > 500
+                            && has_freight
+                            && rng.gen_bool(
+                                (0.05 * sim_state.growth_rate as f64 * land_value_factor)
+                                    .clamp(0.0, 1.0),
+                            )
                         {
                             new_tile = TileType::CommercialOccupied2;
                             changed = true;
@@ -192,8 +374,17 @@ fn update_zones(
                         }
                     }
                     TileType::CommercialOccupied2 => {
-                        if sim_state.c_valve > 1000
-                            && rng.gen_bool((0.05 * sim_state.growth_rate as f64).clamp(0.0, 1.0))
+                        if !powered {
+                            if rng.gen_bool(UNPOWERED_DECAY_CHANCE) {
+                                new_tile = TileType::CommercialOccupied1;
+                                changed = true;
+                            }
+                        } else if sim_state.c_valve > 1000
+                            && has_freight
+                            && rng.gen_bool(
+                                (0.05 * sim_state.growth_rate as f64 * land_value_factor)
+                                    .clamp(0.0, 1.0),
+                            )
                         {
                             new_tile = TileType::CommercialOccupied3;
                             changed = true;
@@ -205,7 +396,12 @@ fn update_zones(
                         }
                     }
                     TileType::CommercialOccupied3 => {
-                        if sim_state.c_valve < 500
+                        if !powered {
+                            if rng.gen_bool(UNPOWERED_DECAY_CHANCE) {
+                                new_tile = TileType::CommercialOccupied2;
+                                changed = true;
+                            }
+                        } else if sim_state.c_valve < 500
                             && rng.gen_bool((0.05 * sim_state.growth_rate as f64).clamp(0.0, 1.0))
                         {
                             new_tile = TileType::CommercialOccupied2;
@@ -231,7 +427,12 @@ fn update_zones(
                         }
                     }
                     TileType::IndustrialOccupied1 => {
-                        if sim_state.i_valve > 500
+                        if !powered {
+                            if rng.gen_bool(UNPOWERED_DECAY_CHANCE) {
+                                new_tile = TileType::Industrial;
+                                changed = true;
+                            }
+                        } else if sim_state.i_valve > 500
                             && rng.gen_bool((0.05 * sim_state.growth_rate as f64).clamp(0.0, 1.0))
                         {
                             new_tile = TileType::IndustrialOccupied2;
@@ -244,7 +445,12 @@ fn update_zones(
                         }
                     }
                     TileType::IndustrialOccupied2 => {
-                        if sim_state.i_valve > 1000
+                        if !powered {
+                            if rng.gen_bool(UNPOWERED_DECAY_CHANCE) {
+                                new_tile = TileType::IndustrialOccupied1;
+                                changed = true;
+                            }
+                        } else if sim_state.i_valve > 1000
                             && rng.gen_bool((0.05 * sim_state.growth_rate as f64).clamp(0.0, 1.0))
                         {
                             new_tile = TileType::IndustrialOccupied3;
@@ -257,7 +463,12 @@ fn update_zones(
                         }
                     }
                     TileType::IndustrialOccupied3 => {
-                        if sim_state.i_valve < 500
+                        if !powered {
+                            if rng.gen_bool(UNPOWERED_DECAY_CHANCE) {
+                                new_tile = TileType::IndustrialOccupied2;
+                                changed = true;
+                            }
+                        } else if sim_state.i_valve < 500
                             && rng.gen_bool((0.05 * sim_state.growth_rate as f64).clamp(0.0, 1.0))
                         {
                             new_tile = TileType::IndustrialOccupied2;
@@ -292,7 +503,25 @@ fn has_road_neighbor(tiles: &[TileType], w: i32, h: i32, x: i32, y: i32) -> bool
 
             if nx >= 0 && nx < w && ny >= 0 && ny < h {
                 let idx = (ny * w + nx) as usize;
-                if tiles[idx] == TileType::Road {
+                if crate::map::is_road(tiles[idx]) {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+fn has_water_neighbor(tiles: &[TileType], w: i32, h: i32, x: i32, y: i32) -> bool {
+    let radius = 3;
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            let nx = x + dx;
+            let ny = y + dy;
+
+            if nx >= 0 && nx < w && ny >= 0 && ny < h {
+                let idx = (ny * w + nx) as usize;
+                if tiles[idx] == TileType::Water {
                     return true;
                 }
             }
@@ -301,7 +530,84 @@ fn has_road_neighbor(tiles: &[TileType], w: i32, h: i32, x: i32, y: i32) -> bool
     false
 }
 
-fn census_system(map: Res<GameMap>, mut sim_state: ResMut<SimulationState>) {
+// Ticks between environment field recomputations (once every 4 hours — slower than power's hourly cadence
+// since pollution/land-value are meant to drift, not react instantly).
+const ENV_FIELD_CADENCE: u64 = 16;
+// Fraction of diffused pollution retained each update step; the rest fades away.
+const POLLUTION_DECAY: f32 = 0.9;
+
+fn update_environment_fields(map: Res<GameMap>, mut fields: ResMut<EnvironmentFields>, sim_state: Res<SimulationState>) {
+    if sim_state.time % ENV_FIELD_CADENCE != 0 {
+        return;
+    }
+
+    let Some(surface) = map.layers.get(&0) else {
+        return;
+    };
+    let width = map.width as i32;
+    let height = map.height as i32;
+    let area = (map.width * map.height) as usize;
+
+    if fields.pollution.len() != area {
+        fields.pollution = vec![0; area];
+        fields.land_value = vec![0; area];
+    }
+
+    // 1. Deposit pollution from occupied industrial zones and dirty power plants.
+    let mut deposit = vec![0u32; area];
+    for (i, &tile) in surface.iter().enumerate() {
+        deposit[i] = match tile {
+            TileType::IndustrialOccupied1 => 20,
+            TileType::IndustrialOccupied2 => 40,
+            TileType::IndustrialOccupied3 => 80,
+            _ if is_power_plant(tile) => plant_pollution(tile) / 10,
+            _ => 0,
+        };
+    }
+
+    // 2. Diffuse: each cell becomes a weighted average of itself, its fresh deposit, and its 4
+    // neighbors' existing levels, then decays so pollution bleeds outward and fades over time.
+    let mut diffused = vec![0u8; area];
+    for y in 0..height {
+        for x in 0..width {
+            let idx = (y * width + x) as usize;
+            let mut total = (fields.pollution[idx] as u32 + deposit[idx]) * 4;
+            let mut weight = 4;
+            for (nx, ny) in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                if nx >= 0 && nx < width && ny >= 0 && ny < height {
+                    total += fields.pollution[(ny * width + nx) as usize] as u32;
+                    weight += 1;
+                }
+            }
+            diffused[idx] = (((total / weight) as f32) * POLLUTION_DECAY).min(255.0) as u8;
+        }
+    }
+    fields.pollution = diffused;
+
+    // 3. Land value rises near the map edge/water and falls with local pollution.
+    let max_span = (width.min(height) / 2).max(1) as f32;
+    for y in 0..height {
+        for x in 0..width {
+            let idx = (y * width + x) as usize;
+            let edge_dist = x.min(width - 1 - x).min(y.min(height - 1 - y)) as f32;
+            let edge_bonus = (1.0 - (edge_dist / max_span).min(1.0)) * 100.0;
+            let water_bonus = if has_water_neighbor(surface, width, height, x, y) {
+                80.0
+            } else {
+                0.0
+            };
+            let pollution_penalty = fields.pollution[idx] as f32 * 0.5;
+            let value = (50.0 + edge_bonus + water_bonus - pollution_penalty).clamp(0.0, 255.0);
+            fields.land_value[idx] = value as u8;
+        }
+    }
+}
+
+fn census_system(
+    map: Res<GameMap>,
+    freight: Res<FreightNetwork>,
+    mut sim_state: ResMut<SimulationState>,
+) {
     // Run census every day (96 ticks)
     if sim_state.time % 96 != 0 {
         return;
@@ -309,9 +615,18 @@ fn census_system(map: Res<GameMap>, mut sim_state: ResMut<SimulationState>) {
 
     let mut r_pop = 0;
     let mut jobs = 0;
+    let mut goods_produced = 0;
+    let mut goods_delivered = 0;
 
     if let Some(layers) = map.layers.get(&0) {
-        for tile in layers {
+        for (i, tile) in layers.iter().enumerate() {
+            let goods = match tile {
+                TileType::IndustrialOccupied1 => 8,
+                TileType::IndustrialOccupied2 => 16,
+                TileType::IndustrialOccupied3 => 24,
+                _ => 0,
+            };
+
             match tile {
                 TileType::ResidentialOccupied1 => r_pop += 8,
                 TileType::ResidentialOccupied2 => r_pop += 16,
@@ -319,169 +634,420 @@ fn census_system(map: Res<GameMap>, mut sim_state: ResMut<SimulationState>) {
                 TileType::CommercialOccupied1 => jobs += 8,
                 TileType::CommercialOccupied2 => jobs += 16,
                 TileType::CommercialOccupied3 => jobs += 24,
-                TileType::IndustrialOccupied1 => jobs += 8,
-                TileType::IndustrialOccupied2 => jobs += 16,
-                TileType::IndustrialOccupied3 => jobs += 24,
+                TileType::IndustrialOccupied1
+                | TileType::IndustrialOccupied2
+                | TileType::IndustrialOccupied3 => jobs += goods,
                 _ => {}
             }
+
+            if goods > 0 {
+                let x = (i as u32 % map.width) as i32;
+                let y = (i as u32 / map.width) as i32;
+                goods_produced += goods;
+                if freight.connected_industrial.contains(&(x, y)) {
+                    goods_delivered += goods;
+                }
+            }
         }
     }
 
     sim_state.total_pop = r_pop;
     sim_state.num_jobs = jobs;
+    sim_state.goods_produced = goods_produced;
+    sim_state.goods_delivered = goods_delivered;
 }
 
-fn update_power_grid(
-    map: Res<GameMap>,
-    mut power_grid: ResMut<PowerGrid>,
+// Sample a bounded fraction of the map each day rather than sweeping every tile, so per-tick
+// cost stays bounded while larger maps still see proportionally more review activity.
+const ZONE_REVIEW_SAMPLE_DIVISOR: u32 = 64;
+const ABANDON_THRESHOLD: u8 = 3;
+
+fn zone_review_system(
+    mut map: ResMut<GameMap>,
     sim_state: Res<SimulationState>,
+    power_grid: Res<PowerGrid>,
+    mut tracker: ResMut<AbandonmentTracker>,
+    mut chunk_events: EventWriter<ChunkUpdateEvent>,
+    mut city_events: EventWriter<CityEvent>,
 ) {
-    if sim_state.time % 4 != 0 {
+    // Run once per in-game day (96 ticks), same cadence as the census.
+    if sim_state.time % 96 != 0 {
         return;
-    } // Update every hour (4 ticks)
+    }
 
-    let mut visited = std::collections::HashSet::new();
-    let mut queue = std::collections::VecDeque::new();
-    let mut supply = 0;
-    let mut demand = 0;
-
-    // 1. Find Power Sources (Plants)
-    if let Some(surface) = map.layers.get(&0) {
-        for (i, tile) in surface.iter().enumerate() {
-            if *tile == TileType::PowerPlant {
-                let x = (i as u32 % map.width) as i32;
-                let y = (i as u32 / map.width) as i32;
-                queue.push_back((x, y));
-                visited.insert((x, y));
-                supply += 500; // Each plant generates 500 units
+    let width = map.width;
+    let height = map.height;
+    let sample_count = ((width * height) / ZONE_REVIEW_SAMPLE_DIVISOR).max(1) as usize;
+    let mut rng = rand::thread_rng();
+
+    let Some(layers) = map.layers.get_mut(&0) else {
+        return;
+    };
+
+    for _ in 0..sample_count {
+        let x = rng.gen_range(0..width);
+        let y = rng.gen_range(0..height);
+        let idx = (y * width + x) as usize;
+        let tile = layers[idx];
+
+        let (category, valve) = match tile {
+            TileType::ResidentialOccupied1
+            | TileType::ResidentialOccupied2
+            | TileType::ResidentialOccupied3 => ("Residential", sim_state.r_valve),
+            TileType::CommercialOccupied1
+            | TileType::CommercialOccupied2
+            | TileType::CommercialOccupied3 => ("Commercial", sim_state.c_valve),
+            TileType::IndustrialOccupied1
+            | TileType::IndustrialOccupied2
+            | TileType::IndustrialOccupied3 => ("Industrial", sim_state.i_valve),
+            _ => continue, // Only occupied zones are subject to review.
+        };
+
+        let powered = power_grid.powered_tiles.contains(&(x as i32, y as i32));
+        let has_road =
+            has_road_neighbor(layers, width as i32, height as i32, x as i32, y as i32);
+
+        let failure_cause = if !powered {
+            Some("no power")
+        } else if valve < -500 {
+            Some("no demand")
+        } else if !has_road {
+            Some("no road access")
+        } else {
+            None
+        };
+
+        let coord = (x as i32, y as i32);
+        match failure_cause {
+            Some(reason) => {
+                let strikes = tracker.strikes.entry(coord).or_insert(0);
+                *strikes += 1;
+                if *strikes >= ABANDON_THRESHOLD {
+                    layers[idx] = TileType::Rubble;
+                    tracker.strikes.remove(&coord);
+                    chunk_events.send(ChunkUpdateEvent {
+                        chunk_x: x / 32,
+                        chunk_z: y / 32,
+                    });
+                    city_events.send(CityEvent {
+                        cause: format!("{} zone abandoned — {}", category, reason),
+                        x: coord.0,
+                        y: coord.1,
+                    });
+                }
+            }
+            None => {
+                tracker.strikes.remove(&coord);
             }
         }
     }
+}
 
-    // 2. BFS Network Propagation
-    let mut network_nodes = std::collections::HashSet::new();
-    while let Some((cx, cy)) = queue.pop_front() {
-        network_nodes.insert((cx, cy));
+// Must match `rendering::CHUNK_SIZE` — the tile footprint of a single `ChunkUpdateEvent`.
+const POWER_CHUNK_TILE_SIZE: i32 = 32;
 
-        let neighbors = [(cx + 1, cy), (cx - 1, cy), (cx, cy + 1), (cx, cy - 1)];
+/// One connected component of the power network (conductors reachable from each other). Cached
+/// across ticks so an edit only forces a re-flood of the component(s) it actually touches.
+struct PowerComponent {
+    tiles: std::collections::HashSet<(i32, i32)>,
+    // BFS pop order from this component's own plants, nearest-to-plant first, reused for
+    // proportional load-shedding.
+    visit_order: Vec<(i32, i32)>,
+    supply: i32,
+    pollution: u32,
+}
 
-        for (nx, ny) in neighbors {
-            if nx >= 0 && nx < map.width as i32 && ny >= 0 && ny < map.height as i32 {
-                if !visited.contains(&(nx, ny)) {
-                    let idx = (ny as u32 * map.width + nx as u32) as usize;
-                    let mut conducted = false;
+/// Persistent partition of conductor tiles into connected components, keyed by tile so a dirty
+/// chunk can look up exactly which component(s) it invalidates. `dirty_chunks` accumulates
+/// `ChunkUpdateEvent`s between power ticks.
+#[derive(Resource, Default)]
+pub struct PowerNetworkCache {
+    components: Vec<Option<PowerComponent>>,
+    component_of: std::collections::HashMap<(i32, i32), usize>,
+    dirty_chunks: std::collections::HashSet<(u32, u32)>,
+}
 
-                    // Check Surface (Buildings/Plants)
-                    if let Some(surface) = map.layers.get(&0) {
-                        let tile = surface[idx];
-                        if is_conductor(tile) {
-                            conducted = true;
-                        }
-                    }
+fn mark_power_dirty(
+    mut events: EventReader<ChunkUpdateEvent>,
+    mut cache: ResMut<PowerNetworkCache>,
+) {
+    for event in events.read() {
+        cache.dirty_chunks.insert((event.chunk_x, event.chunk_z));
+    }
+}
 
-                    // Check Air (Power Lines)
-                    if !conducted {
-                        if let Some(air) = map.layers.get(&1) {
-                            if air[idx] == TileType::PowerLine {
-                                conducted = true;
-                            }
-                        }
-                    }
+fn tile_at(surface: &[TileType], width: u32, (x, y): (i32, i32)) -> TileType {
+    surface[(y as u32 * width + x as u32) as usize]
+}
 
-                    if conducted {
-                        visited.insert((nx, ny));
-                        queue.push_back((nx, ny));
-                    }
+fn conducts_at(surface: &[TileType], air: Option<&Vec<TileType>>, width: u32, coord: (i32, i32)) -> bool {
+    if is_conductor(tile_at(surface, width, coord)) {
+        return true;
+    }
+    if let Some(air) = air {
+        let idx = (coord.1 as u32 * width + coord.0 as u32) as usize;
+        if air[idx] == TileType::PowerLine {
+            return true;
+        }
+    }
+    false
+}
+
+fn update_power_grid(
+    map: Res<GameMap>,
+    mut power_grid: ResMut<PowerGrid>,
+    mut cache: ResMut<PowerNetworkCache>,
+    mut sim_state: ResMut<SimulationState>,
+) {
+    if sim_state.time % 4 != 0 {
+        return;
+    } // Update every hour (4 ticks)
+
+    if cache.dirty_chunks.is_empty() && !cache.component_of.is_empty() {
+        // Nothing touched the grid since the last recompute; cached totals are still valid.
+        return;
+    }
+
+    let Some(surface) = map.layers.get(&0) else {
+        return;
+    };
+    let air = map.layers.get(&1);
+    let width = map.width as i32;
+    let height = map.height as i32;
+
+    // 1. Invalidate every cached component overlapping a dirty chunk (plus a 1-tile halo, so an
+    // edit at a chunk boundary correctly re-merges/splits the neighboring network), and seed the
+    // re-flood with their tiles.
+    let mut stale_ids = std::collections::HashSet::new();
+    for &(chunk_x, chunk_z) in &cache.dirty_chunks {
+        let sx = (chunk_x as i32 * POWER_CHUNK_TILE_SIZE - 1).max(0);
+        let sz = (chunk_z as i32 * POWER_CHUNK_TILE_SIZE - 1).max(0);
+        let ex = ((chunk_x as i32 + 1) * POWER_CHUNK_TILE_SIZE).min(width - 1);
+        let ez = ((chunk_z as i32 + 1) * POWER_CHUNK_TILE_SIZE).min(height - 1);
+        for y in sz..=ez {
+            for x in sx..=ex {
+                if let Some(&id) = cache.component_of.get(&(x, y)) {
+                    stale_ids.insert(id);
                 }
             }
         }
     }
 
-    // 3. Calculate Demand & Power Status
-    power_grid.powered_tiles.clear();
+    let mut reflood_seeds = std::collections::HashSet::new();
+    for id in stale_ids {
+        if let Some(component) = cache.components[id].take() {
+            for tile in component.tiles {
+                cache.component_of.remove(&tile);
+                reflood_seeds.insert(tile);
+            }
+        }
+    }
+
+    // Also seed every conductor tile freshly inside a dirty chunk (e.g. a brand new power line
+    // or plant that was not previously part of any component).
+    for &(chunk_x, chunk_z) in &cache.dirty_chunks {
+        let sx = chunk_x as i32 * POWER_CHUNK_TILE_SIZE;
+        let sz = chunk_z as i32 * POWER_CHUNK_TILE_SIZE;
+        let ex = (sx + POWER_CHUNK_TILE_SIZE).min(width);
+        let ez = (sz + POWER_CHUNK_TILE_SIZE).min(height);
+        for y in sz..ez {
+            for x in sx..ex {
+                if conducts_at(surface, air, map.width, (x, y)) {
+                    reflood_seeds.insert((x, y));
+                }
+            }
+        }
+    }
+    cache.dirty_chunks.clear();
 
-    // Check neighbors of network nodes for consumers (radius 1)
-    // Actually, network nodes themselves are powered if they are buildings
-    // AND adjacent buildings touching the network get power
+    // 2. Re-flood only from the seeds. Tiles already owned by a still-valid component are
+    // skipped, so the BFS cost is proportional to the networks actually touched this tick.
+    for seed in reflood_seeds {
+        if cache.component_of.contains_key(&seed) || !conducts_at(surface, air, map.width, seed) {
+            continue;
+        }
 
-    // Simplified: Any consumer touching a 'Network Node' or IS a 'Network Node' is potentially powered.
-    // We already traversed conductors. Now let's calculate demand for all connected conductors.
+        // First pass: discover the full connected component from `seed` so we know where its
+        // power plants live. Load-shedding must walk consumers in order of distance *from those
+        // plants*, not from whichever arbitrary conductor happened to seed the re-flood.
+        let mut discovered = std::collections::HashSet::new();
+        let mut discover_queue = std::collections::VecDeque::new();
+        discover_queue.push_back(seed);
+        discovered.insert(seed);
+        while let Some((cx, cy)) = discover_queue.pop_front() {
+            for (nx, ny) in [(cx + 1, cy), (cx - 1, cy), (cx, cy + 1), (cx, cy - 1)] {
+                if nx >= 0
+                    && nx < width
+                    && ny >= 0
+                    && ny < height
+                    && !discovered.contains(&(nx, ny))
+                    && conducts_at(surface, air, map.width, (nx, ny))
+                {
+                    discovered.insert((nx, ny));
+                    discover_queue.push_back((nx, ny));
+                }
+            }
+        }
 
-    let mut consumers = std::collections::HashSet::new();
+        // Second pass: multi-source BFS rooted at every plant tile in the component (falling
+        // back to `seed` if the component has no plant at all), so `visit_order` is genuinely
+        // plant-rooted: tiles electrically farthest from a plant come last.
+        let mut plant_roots: Vec<_> = discovered
+            .iter()
+            .copied()
+            .filter(|&t| is_power_plant(tile_at(surface, map.width, t)))
+            .collect();
+        plant_roots.sort_unstable();
+        let roots: &[(i32, i32)] = if plant_roots.is_empty() {
+            std::slice::from_ref(&seed)
+        } else {
+            &plant_roots
+        };
 
-    if let Some(surface) = map.layers.get(&0) {
-        for &(cx, cy) in &network_nodes {
-            // Check self
-            let idx = (cy as u32 * map.width + cx as u32) as usize;
-            let tile_type = surface[idx];
-            let cons = get_power_consumption(tile_type);
-            if cons > 0 {
-                consumers.insert((cx, cy));
-                demand += cons;
+        let mut visited = std::collections::HashSet::new();
+        let mut visit_order = Vec::new();
+        let mut queue = std::collections::VecDeque::new();
+        let mut supply = 0;
+        let mut pollution = 0;
+        for &root in roots {
+            visited.insert(root);
+            queue.push_back(root);
+        }
+
+        while let Some((cx, cy)) = queue.pop_front() {
+            visit_order.push((cx, cy));
+            let tile = tile_at(surface, map.width, (cx, cy));
+            if is_power_plant(tile) {
+                supply += plant_supply(tile);
+                pollution += plant_pollution(tile);
             }
 
-            // Check immediate neighbors (Buildings connect to lines)
-            let neighbors = [(cx + 1, cy), (cx - 1, cy), (cx, cy + 1), (cx, cy - 1)];
-            for (nx, ny) in neighbors {
-                if nx >= 0 && nx < map.width as i32 && ny >= 0 && ny < map.height as i32 {
-                    let nidx = (ny as u32 * map.width + nx as u32) as usize;
-                    let ntile = surface[nidx];
-                    let ncons = get_power_consumption(ntile);
-                    if ncons > 0 {
-                        consumers.insert((nx, ny));
-                        // Note: We might double count if we iterate simplistically,
-                        // but using a set for consumers solves uniqueness.
-                        // However demand calculation needs to iterate the set AFTER finding all consumers.
-                    }
+            for (nx, ny) in [(cx + 1, cy), (cx - 1, cy), (cx, cy + 1), (cx, cy - 1)] {
+                if nx >= 0
+                    && nx < width
+                    && ny >= 0
+                    && ny < height
+                    && !visited.contains(&(nx, ny))
+                    && conducts_at(surface, air, map.width, (nx, ny))
+                {
+                    visited.insert((nx, ny));
+                    queue.push_back((nx, ny));
                 }
             }
         }
-    }
 
-    // Recalculate true demand from unique set
-    demand = 0;
-    if let Some(surface) = map.layers.get(&0) {
-        for &(cx, cy) in &consumers {
-            let idx = (cy as u32 * map.width + cx as u32) as usize;
-            let tile = surface[idx];
-            demand += get_power_consumption(tile);
+        let id = cache
+            .components
+            .iter()
+            .position(Option::is_none)
+            .unwrap_or(cache.components.len());
+        if id == cache.components.len() {
+            cache.components.push(None);
         }
+        for &tile in &visited {
+            cache.component_of.insert(tile, id);
+        }
+        cache.components[id] = Some(PowerComponent {
+            tiles: visited,
+            visit_order,
+            supply,
+            pollution,
+        });
     }
 
-    power_grid.net_power = supply - demand;
+    // 3. Recombine every live component into the public output. Load-shedding runs within each
+    // component independently (disconnected grids cannot share capacity), walking consumers in
+    // BFS order from that component's own plants and keeping tiles powered until the running
+    // total would exceed its supply.
+    power_grid.powered_tiles.clear();
+    let mut total_supply = 0;
+    let mut total_demand = 0;
+    let mut powered_demand = 0;
+    let mut total_pollution = 0;
+    for component in cache.components.iter().flatten() {
+        total_supply += component.supply;
+        total_pollution += component.pollution;
 
-    // 4. Set Powered Status (Brownout Logic)
-    if supply >= demand {
-        power_grid.powered_tiles = consumers;
-        // Also include the lines/plants themselves visual feedback?
-        // Maybe separate? For now, powered_tiles tracks CONSUMERS with power.
-        // Let's add network nodes too so lines glow?
-        // User asked for "residental buildings should display wheteher they have power"
-        // So tracking consumers is the priority.
-    } else {
-        // Brownout! Nobody gets power (or random subset? SimCity 1 just flickered brownouts)
-        // For simplicity: No power if overloaded.
-        power_grid.powered_tiles.clear();
+        let mut running_total = 0;
+        for &coord in &component.visit_order {
+            let cons = get_power_consumption(tile_at(surface, map.width, coord));
+            if cons == 0 {
+                continue;
+            }
+            total_demand += cons;
+            if running_total + cons > component.supply {
+                continue;
+            }
+            running_total += cons;
+            powered_demand += cons;
+            power_grid.powered_tiles.insert(coord);
+        }
     }
+
+    power_grid.net_power = total_supply - total_demand;
+    power_grid.lit_fraction = if total_demand > 0 {
+        powered_demand as f32 / total_demand as f32
+    } else {
+        1.0
+    };
+    sim_state.pollution = total_pollution;
 }
 
 fn is_conductor(t: TileType) -> bool {
+    is_power_plant(t)
+        || matches!(
+            t,
+            TileType::PowerLine
+                | TileType::ResidentialOccupied1
+                | TileType::ResidentialOccupied2
+                | TileType::ResidentialOccupied3
+                | TileType::CommercialOccupied1
+                | TileType::CommercialOccupied2
+                | TileType::CommercialOccupied3
+                | TileType::IndustrialOccupied1
+                | TileType::IndustrialOccupied2
+                | TileType::IndustrialOccupied3
+        )
+}
+
+pub fn is_power_plant(t: TileType) -> bool {
     matches!(
         t,
-        TileType::PowerPlant
-            | TileType::PowerLine
-            | TileType::ResidentialOccupied1
-            | TileType::ResidentialOccupied2
-            | TileType::ResidentialOccupied3
-            | TileType::CommercialOccupied1
-            | TileType::CommercialOccupied2
-            | TileType::CommercialOccupied3
-            | TileType::IndustrialOccupied1
-            | TileType::IndustrialOccupied2
-            | TileType::IndustrialOccupied3
+        TileType::PowerPlantCoal
+            | TileType::PowerPlantGas
+            | TileType::PowerPlantNuclear
+            | TileType::PowerPlantSolar
+            | TileType::PowerPlantWind
     )
 }
 
+/// Generation capacity per plant kind. Nuclear is the dense baseload option, solar/wind are
+/// lower-yield, mirroring how Egregoria separates renewable from thermal generation. Every kind,
+/// solar and wind included, yields a fixed amount; there is no weather or time-of-day variation.
+pub fn plant_supply(t: TileType) -> i32 {
+    match t {
+        TileType::PowerPlantCoal => 500,
+        TileType::PowerPlantGas => 400,
+        TileType::PowerPlantNuclear => 2000,
+        TileType::PowerPlantSolar => 200,
+        TileType::PowerPlantWind => 150,
+        _ => 0,
+    }
+}
+
+/// Pollution contributed per plant kind each time the grid recomputes. Fossil plants are dirty,
+/// renewables are clean, giving the player a real tradeoff between cheap capacity and air quality.
+pub fn plant_pollution(t: TileType) -> u32 {
+    match t {
+        TileType::PowerPlantCoal => 20,
+        TileType::PowerPlantGas => 10,
+        TileType::PowerPlantNuclear => 2,
+        TileType::PowerPlantSolar => 0,
+        TileType::PowerPlantWind => 0,
+        _ => 0,
+    }
+}
+
 pub fn get_power_consumption(t: TileType) -> i32 {
     match t {
         TileType::ResidentialOccupied1 => 1,
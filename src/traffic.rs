@@ -0,0 +1,297 @@
+use crate::map::TileType;
+use crate::{GameMap, GameState};
+use bevy::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+/// Seconds of fixed-step time between spawn attempts — keeps the agent population from exploding on a large city.
+const SPAWN_INTERVAL: f32 = 1.0;
+/// Cells an agent advances per `move_agents_system` tick; keeps commutes visible over several
+/// frames instead of agents teleporting straight to their destination.
+const STEPS_PER_TICK: usize = 1;
+/// `density` lost per tile per `decay_system` pass, so congestion reflects recent traffic rather
+/// than an ever-growing lifetime total.
+const DECAY_PER_TICK: u16 = 1;
+/// Density value treated as "fully congested" by `density_at`/the overlay color ramp, and in turn
+/// by the growth-suppression factor `simulation::update_zones` derives from it.
+const CONGESTION_SATURATION: u16 = 20;
+
+/// Per-tile count of citizen agents that have crossed it recently — a live congestion signal read
+/// by the inspector, the debug overlay, and `simulation::update_zones`.
+#[derive(Resource)]
+pub struct TrafficMap {
+    width: u32,
+    height: u32,
+    density: Vec<u16>,
+}
+
+impl TrafficMap {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            density: vec![0; (width * height) as usize],
+        }
+    }
+
+    fn idx(&self, x: i32, z: i32) -> Option<usize> {
+        if x < 0 || x >= self.width as i32 || z < 0 || z >= self.height as i32 {
+            return None;
+        }
+        Some((z as u32 * self.width + x as u32) as usize)
+    }
+
+    fn bump(&mut self, x: i32, z: i32) {
+        if let Some(idx) = self.idx(x, z) {
+            self.density[idx] = self.density[idx].saturating_add(1);
+        }
+    }
+}
+
+impl Default for TrafficMap {
+    fn default() -> Self {
+        Self::new(64, 64)
+    }
+}
+
+/// Live traffic count on `(x, z)`, reported by the inspector in `main::handle_interaction`.
+pub fn tile_agent_count(traffic: &TrafficMap, x: i32, z: i32) -> u16 {
+    traffic
+        .idx(x, z)
+        .map(|idx| traffic.density[idx])
+        .unwrap_or(0)
+}
+
+/// Normalized 0..1 congestion on `(x, z)`, used to color the debug overlay.
+pub fn density_at(traffic: &TrafficMap, x: i32, z: i32) -> f32 {
+    tile_agent_count(traffic, x, z) as f32 / CONGESTION_SATURATION as f32
+}
+
+/// A commuting citizen walking a precomputed road route from home to work.
+#[derive(Component)]
+pub struct CitizenAgent {
+    path: Vec<(i32, i32)>,
+    step: usize,
+}
+
+/// Current map-space tile of an agent, mirrored into `Transform` by `move_agents_system`.
+#[derive(Component, Clone, Copy)]
+pub struct TilePosition {
+    pub x: i32,
+    pub z: i32,
+}
+
+/// Whether `(x, z)` is a job tile agents path toward (occupied Commercial or Industrial).
+fn is_job_tile(tile: TileType) -> bool {
+    matches!(
+        tile,
+        TileType::CommercialOccupied1
+            | TileType::CommercialOccupied2
+            | TileType::CommercialOccupied3
+            | TileType::IndustrialOccupied1
+            | TileType::IndustrialOccupied2
+            | TileType::IndustrialOccupied3
+    )
+}
+
+/// BFS over 4-connected road cells (any class, see `map::is_road`) from `start` to the nearest road tile adjacent to
+/// a job tile, returning the route (inclusive of `start`) if one exists.
+fn find_route(surface: &[TileType], width: i32, height: i32, start: (i32, i32)) -> Option<Vec<(i32, i32)>> {
+    let adjacent_to_job = |x: i32, z: i32| {
+        [(x + 1, z), (x - 1, z), (x, z + 1), (x, z - 1)]
+            .into_iter()
+            .filter(|&(nx, nz)| nx >= 0 && nx < width && nz >= 0 && nz < height)
+            .any(|(nx, nz)| is_job_tile(surface[(nz * width + nx) as usize]))
+    };
+
+    let mut visited = HashSet::new();
+    let mut prev: std::collections::HashMap<(i32, i32), (i32, i32)> = std::collections::HashMap::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+    visited.insert(start);
+
+    while let Some((x, z)) = queue.pop_front() {
+        if adjacent_to_job(x, z) {
+            let mut path = vec![(x, z)];
+            let mut cur = (x, z);
+            while let Some(&p) = prev.get(&cur) {
+                path.push(p);
+                cur = p;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for (nx, nz) in [(x + 1, z), (x - 1, z), (x, z + 1), (x, z - 1)] {
+            if nx < 0 || nx >= width || nz < 0 || nz >= height || visited.contains(&(nx, nz)) {
+                continue;
+            }
+            if crate::map::is_road(surface[(nz * width + nx) as usize]) {
+                visited.insert((nx, nz));
+                prev.insert((nx, nz), (x, z));
+                queue.push_back((nx, nz));
+            }
+        }
+    }
+
+    None
+}
+
+/// Periodically spawns a `CitizenAgent` from an occupied Residential tile's nearest road entry
+/// point, routed toward the nearest reachable job tile via `find_route`.
+fn spawn_agents_system(
+    mut commands: Commands,
+    map: Res<GameMap>,
+    time: Res<Time>,
+    mut spawn_timer: Local<f32>,
+) {
+    *spawn_timer += time.delta_secs();
+    if *spawn_timer < SPAWN_INTERVAL {
+        return;
+    }
+    *spawn_timer = 0.0;
+
+    let Some(surface) = map.layers.get(&0) else {
+        return;
+    };
+    let width = map.width as i32;
+    let height = map.height as i32;
+
+    for (i, &tile) in surface.iter().enumerate() {
+        if !matches!(
+            tile,
+            TileType::ResidentialOccupied1
+                | TileType::ResidentialOccupied2
+                | TileType::ResidentialOccupied3
+        ) {
+            continue;
+        }
+        let (x, z) = ((i as u32 % map.width) as i32, (i as u32 / map.width) as i32);
+
+        let Some(entry) = [(x + 1, z), (x - 1, z), (x, z + 1), (x, z - 1)]
+            .into_iter()
+            .find(|&(nx, nz)| {
+                nx >= 0
+                    && nx < width
+                    && nz >= 0
+                    && nz < height
+                    && crate::map::is_road(surface[(nz * width + nx) as usize])
+            })
+        else {
+            continue;
+        };
+
+        if let Some(path) = find_route(surface, width, height, entry) {
+            commands.spawn((
+                CitizenAgent { path, step: 0 },
+                TilePosition { x: entry.0, z: entry.1 },
+                Transform::from_xyz(entry.0 as f32 + 0.5, 0.6, entry.1 as f32 + 0.5),
+                GlobalTransform::default(),
+                Visibility::default(),
+                InheritedVisibility::default(),
+                ViewVisibility::default(),
+            ));
+        }
+    }
+}
+
+/// Advances every agent one step along its route, bumping `TrafficMap` density at the tile it
+/// enters; agents that reach the end of their route despawn (their commute is complete).
+fn move_agents_system(
+    mut commands: Commands,
+    mut traffic: ResMut<TrafficMap>,
+    mut agents: Query<(Entity, &mut CitizenAgent, &mut TilePosition, &mut Transform)>,
+) {
+    for (entity, mut agent, mut position, mut transform) in &mut agents {
+        for _ in 0..STEPS_PER_TICK {
+            agent.step += 1;
+            let Some(&(x, z)) = agent.path.get(agent.step) else {
+                commands.entity(entity).despawn();
+                break;
+            };
+            position.x = x;
+            position.z = z;
+            transform.translation.x = x as f32 + 0.5;
+            transform.translation.z = z as f32 + 0.5;
+            traffic.bump(x, z);
+        }
+    }
+}
+
+/// Resizes `TrafficMap` to match the current map (e.g. after New Game/Load) and decays every
+/// tile's density by `DECAY_PER_TICK`, so congestion reflects recent traffic rather than growing
+/// without bound.
+fn decay_system(mut traffic: ResMut<TrafficMap>, map: Res<GameMap>) {
+    if traffic.width != map.width || traffic.height != map.height {
+        *traffic = TrafficMap::new(map.width, map.height);
+        return;
+    }
+    for density in &mut traffic.density {
+        *density = density.saturating_sub(DECAY_PER_TICK);
+    }
+}
+
+/// Toggled by `M`; when on, `draw_traffic_overlay` colors every road tile by its congestion.
+#[derive(Resource, Default)]
+pub struct TrafficOverlay {
+    pub visible: bool,
+}
+
+fn toggle_traffic_overlay_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<TrafficOverlay>,
+) {
+    if keys.just_pressed(KeyCode::KeyM) {
+        overlay.visible = !overlay.visible;
+    }
+}
+
+/// Draws a colored cuboid over every road tile when the overlay is on, ramping green (clear) to
+/// red (saturated) by `density_at`.
+fn draw_traffic_overlay(
+    overlay: Res<TrafficOverlay>,
+    traffic: Res<TrafficMap>,
+    map: Res<GameMap>,
+    mut gizmos: Gizmos,
+) {
+    if !overlay.visible {
+        return;
+    }
+    let Some(surface) = map.layers.get(&0) else {
+        return;
+    };
+
+    for (i, &tile) in surface.iter().enumerate() {
+        if !crate::map::is_road(tile) {
+            continue;
+        }
+        let (x, z) = ((i as u32 % map.width) as i32, (i as u32 / map.width) as i32);
+        let congestion = density_at(&traffic, x, z).min(1.0);
+        let color = Color::srgb(congestion, 1.0 - congestion, 0.0);
+        gizmos.cuboid(
+            Transform::from_xyz(x as f32 + 0.5, 1.15, z as f32 + 0.5)
+                .with_scale(Vec3::new(0.9, 0.1, 0.9)),
+            color,
+        );
+    }
+}
+
+pub struct TrafficPlugin;
+
+impl Plugin for TrafficPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TrafficMap>()
+            .init_resource::<TrafficOverlay>()
+            .add_systems(
+                FixedUpdate,
+                (decay_system, spawn_agents_system, move_agents_system)
+                    .chain()
+                    .run_if(in_state(GameState::Game)),
+            )
+            .add_systems(
+                Update,
+                (toggle_traffic_overlay_system, draw_traffic_overlay)
+                    .chain()
+                    .run_if(in_state(GameState::Game)),
+            );
+    }
+}
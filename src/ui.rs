@@ -1,12 +1,50 @@
-use crate::{rendering::ViewMode, GameMap, GameState};
+use crate::{map::TileType, rendering::ViewMode, GameMap, GameState};
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct MenuState {
     pub map_size_idx: usize,
     pub water_percent: f32,
     pub show_config: bool,
+    pub octaves: u32,
+    pub persistence: f32,
+    pub lacunarity: f32,
+    pub river_count: u32,
+    /// Set when "Load Game" fails or after any "Save City" attempt (success or failure), shown
+    /// under the relevant button until the next attempt.
+    pub status: Option<String>,
+}
+
+impl Default for MenuState {
+    fn default() -> Self {
+        Self {
+            map_size_idx: 0,
+            water_percent: 0.0,
+            show_config: false,
+            octaves: 5,
+            persistence: 0.5,
+            lacunarity: 2.0,
+            river_count: 3,
+            status: None,
+        }
+    }
+}
+
+/// Fixed save-file path for New/Load/Save — there's no file-picker dependency in this project yet,
+/// so a single on-disk slot stands in for one until that lands.
+const SAVE_PATH: &str = "save.city";
+
+/// Side length (tiles) of the square footprint paint-style tools stamp around the cursor; even
+/// values round up to the next odd width, since the footprint is centered (see `brush_offsets`),
+/// following the land-tool size control pattern from OpenTTD/OpenRCT2.
+#[derive(Resource, Clone, Copy)]
+pub struct BrushSize(pub u32);
+
+impl Default for BrushSize {
+    fn default() -> Self {
+        Self(1)
+    }
 }
 
 #[derive(Resource, Default, PartialEq, Eq, Clone, Copy, Debug)]
@@ -15,11 +53,426 @@ pub enum ToolState {
     Select,
     Bulldozer,
     Road,
+    Avenue,
+    Highway,
+    RoadOneWay,
     Residential,
     Commercial,
     Industrial,
     PowerLine,
-    PowerPlant,
+    PowerPlantCoal,
+    PowerPlantGas,
+    PowerPlantNuclear,
+    PowerPlantSolar,
+    PowerPlantWind,
+    Subway,
+    Pipe,
+    Fill,
+}
+
+/// Flood-fill paint target for `ToolState::Fill`, read by `main::apply_tool` and set via the
+/// Tools window's Fill selector. Unlike the other zone tools, Fill isn't itself tied to one tile
+/// type — it repaints whatever contiguous region the player clicks — so the target has to be its
+/// own piece of state rather than derived from the selected tool.
+#[derive(Resource, Clone, Copy)]
+pub struct FillTarget(pub TileType);
+
+impl Default for FillTarget {
+    fn default() -> Self {
+        Self(TileType::Residential)
+    }
+}
+
+const FILL_TARGETS: &[(TileType, &str)] = &[
+    (TileType::Dirt, "Dirt"),
+    (TileType::Residential, "Residential"),
+    (TileType::Commercial, "Commercial"),
+    (TileType::Industrial, "Industrial"),
+    (TileType::Road, "Road"),
+    (TileType::Avenue, "Avenue"),
+    (TileType::Highway, "Highway"),
+    (TileType::Water, "Water"),
+];
+
+/// One entry in a Tools dropdown: the tool it selects, its button label, and the single-key
+/// shortcut that also selects it (shown in the dropdown so the shortcut stays discoverable).
+#[derive(Clone, Copy)]
+struct ToolBinding {
+    tool: ToolState,
+    label: &'static str,
+    key: KeyCode,
+    key_label: &'static str,
+}
+
+// WASD/Q/E are already claimed by camera panning/rotation (see `camera_controller` in
+// rendering.rs) and G by the grid toggle, so these categories avoid them.
+const EDIT_TOOLS: &[ToolBinding] = &[
+    ToolBinding {
+        tool: ToolState::Select,
+        label: "👆 Select",
+        key: KeyCode::KeyZ,
+        key_label: "Z",
+    },
+    ToolBinding {
+        tool: ToolState::Bulldozer,
+        label: "🚜 Doze",
+        key: KeyCode::KeyB,
+        key_label: "B",
+    },
+    ToolBinding {
+        tool: ToolState::Fill,
+        label: "🪣 Fill",
+        key: KeyCode::KeyF,
+        key_label: "F",
+    },
+];
+
+const ZONE_TOOLS: &[ToolBinding] = &[
+    ToolBinding {
+        tool: ToolState::Residential,
+        label: "🏠 Res",
+        key: KeyCode::Digit1,
+        key_label: "1",
+    },
+    ToolBinding {
+        tool: ToolState::Commercial,
+        label: "🏢 Com",
+        key: KeyCode::Digit2,
+        key_label: "2",
+    },
+    ToolBinding {
+        tool: ToolState::Industrial,
+        label: "🏭 Ind",
+        key: KeyCode::Digit3,
+        key_label: "3",
+    },
+];
+
+const INFRASTRUCTURE_TOOLS: &[ToolBinding] = &[
+    ToolBinding {
+        tool: ToolState::Road,
+        label: "🛣️ Road",
+        key: KeyCode::KeyR,
+        key_label: "R",
+    },
+    ToolBinding {
+        tool: ToolState::Avenue,
+        label: "🛣️ Avenue",
+        key: KeyCode::KeyV,
+        key_label: "V",
+    },
+    ToolBinding {
+        tool: ToolState::Highway,
+        label: "🛣️ Highway",
+        key: KeyCode::KeyH,
+        key_label: "H",
+    },
+    ToolBinding {
+        tool: ToolState::RoadOneWay,
+        label: "➡️ One-Way",
+        key: KeyCode::KeyU,
+        key_label: "U",
+    },
+    ToolBinding {
+        tool: ToolState::PowerLine,
+        label: "🔌 Line",
+        key: KeyCode::KeyP,
+        key_label: "P",
+    },
+    ToolBinding {
+        tool: ToolState::PowerPlantCoal,
+        label: "⚡ Coal",
+        key: KeyCode::KeyC,
+        key_label: "C",
+    },
+    ToolBinding {
+        tool: ToolState::PowerPlantGas,
+        label: "⚡ Gas",
+        key: KeyCode::KeyX,
+        key_label: "X",
+    },
+    ToolBinding {
+        tool: ToolState::PowerPlantNuclear,
+        label: "⚡ Nuclear",
+        key: KeyCode::KeyN,
+        key_label: "N",
+    },
+    ToolBinding {
+        tool: ToolState::PowerPlantSolar,
+        label: "☀ Solar",
+        key: KeyCode::KeyO,
+        key_label: "O",
+    },
+    ToolBinding {
+        tool: ToolState::PowerPlantWind,
+        label: "🌬 Wind",
+        key: KeyCode::KeyI,
+        key_label: "I",
+    },
+];
+
+/// How a left-click drag is interpreted, recomputed every frame from held modifier keys by
+/// `update_draw_mode_system` so `main::handle_interaction`/`draw_preview_gizmos` can both read it.
+/// `BrushSize` still owns the footprint's size — this only controls how that footprint is
+/// dragged across the map.
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DrawMode {
+    /// Default: fills the dragged rectangle (or lays a road line end-to-end).
+    #[default]
+    Drag,
+    /// Shift held: clamps the drag to its dominant axis, turning a rectangle into a straight line.
+    AxisLock,
+    /// Ctrl held: stamps the brush footprint under the cursor on every frame it moves, rather than
+    /// only computing a shape from start to end on release.
+    Freehand,
+}
+
+/// Recomputes `DrawMode` from Shift/Ctrl each frame. Ctrl takes priority over Shift since
+/// freehand painting makes the axis-lock distinction moot (there's no single drag line to clamp).
+pub(crate) fn update_draw_mode_system(
+    mut mode: ResMut<DrawMode>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    let ctrl = keys.pressed(KeyCode::ControlLeft) || keys.pressed(KeyCode::ControlRight);
+    let shift = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    *mode = if ctrl {
+        DrawMode::Freehand
+    } else if shift {
+        DrawMode::AxisLock
+    } else {
+        DrawMode::Drag
+    };
+}
+
+/// Sets `ToolState` directly from each category's single-key shortcuts (see `ToolBinding`),
+/// independent of whether the Tools window is open or which dropdown is expanded.
+fn tool_hotkey_system(mut tool_state: ResMut<ToolState>, keys: Res<ButtonInput<KeyCode>>) {
+    // Ctrl+Z/Ctrl+Y are claimed by `history::undo_redo_system`; don't let the bare Z hotkey
+    // (Select tool) fire underneath a held Ctrl. Shift+B is claimed by `stats_hotkey_system`;
+    // don't let the bare B hotkey (Bulldozer) fire underneath a held Shift either.
+    if keys.pressed(KeyCode::ControlLeft)
+        || keys.pressed(KeyCode::ControlRight)
+        || keys.pressed(KeyCode::ShiftLeft)
+        || keys.pressed(KeyCode::ShiftRight)
+    {
+        return;
+    }
+    for binding in EDIT_TOOLS
+        .iter()
+        .chain(ZONE_TOOLS)
+        .chain(INFRASTRUCTURE_TOOLS)
+    {
+        if keys.just_pressed(binding.key) {
+            *tool_state = binding.tool;
+        }
+    }
+}
+
+/// Renders a labeled `ComboBox` for one tool category, selected-text showing the active tool
+/// (or the category name if none of its tools is currently selected) and each entry annotated
+/// with its hotkey, mirroring the toolbar-to-dropdown conversion used by Widelands.
+fn tool_category_combo(
+    ui: &mut egui::Ui,
+    id_salt: &str,
+    category: &str,
+    bindings: &[ToolBinding],
+    tool_state: &mut ToolState,
+) {
+    let selected_text = bindings
+        .iter()
+        .find(|binding| binding.tool == *tool_state)
+        .map(|binding| binding.label)
+        .unwrap_or(category);
+
+    ui.horizontal(|ui| {
+        ui.label(category);
+        egui::ComboBox::from_id_salt(id_salt)
+            .selected_text(selected_text)
+            .show_ui(ui, |ui| {
+                for binding in bindings {
+                    ui.selectable_value(
+                        tool_state,
+                        binding.tool,
+                        format!("{} ({})", binding.label, binding.key_label),
+                    );
+                }
+            });
+    });
+}
+
+/// Tile counts for one zone category, bucketed by development level (0 = unoccupied).
+struct ZoneCounts {
+    empty: usize,
+    level1: usize,
+    level2: usize,
+    level3: usize,
+}
+
+impl ZoneCounts {
+    fn developed(&self) -> usize {
+        self.level1 + self.level2 + self.level3
+    }
+
+    fn total(&self) -> usize {
+        self.empty + self.developed()
+    }
+}
+
+fn count_zone(
+    surface: &[TileType],
+    empty: TileType,
+    level1: TileType,
+    level2: TileType,
+    level3: TileType,
+) -> ZoneCounts {
+    let mut counts = ZoneCounts {
+        empty: 0,
+        level1: 0,
+        level2: 0,
+        level3: 0,
+    };
+    for &tile in surface {
+        if tile == empty {
+            counts.empty += 1;
+        } else if tile == level1 {
+            counts.level1 += 1;
+        } else if tile == level2 {
+            counts.level2 += 1;
+        } else if tile == level3 {
+            counts.level3 += 1;
+        }
+    }
+    counts
+}
+
+/// Identifies one floating `egui::Window` the `WindowManager` tracks. Each variant is a fixed
+/// panel in this app (no dynamically-created windows yet), so the manager's maps are keyed
+/// directly by this enum rather than a generic id.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum WindowId {
+    Status,
+    Tools,
+    Inspector,
+    Stats,
+    News,
+}
+
+impl WindowId {
+    fn title(self) -> &'static str {
+        match self {
+            WindowId::Status => "City Status",
+            WindowId::Tools => "Tools",
+            WindowId::Inspector => "Inspector",
+            WindowId::Stats => "City Statistics",
+            WindowId::News => "News",
+        }
+    }
+}
+
+const ALL_WINDOW_IDS: [WindowId; 5] = [
+    WindowId::Status,
+    WindowId::Tools,
+    WindowId::Inspector,
+    WindowId::Stats,
+    WindowId::News,
+];
+
+/// Tracks draw order, open/closed state, and remembered position for every floating panel,
+/// replacing the ad-hoc per-window `visible` bools each panel used to keep on its own resource.
+/// `order` is back-to-front: `window_dispatcher_system` shows windows in this sequence each frame,
+/// so the last entry is drawn last and therefore sits on top, and interacting with any window
+/// raises it to that position (see `raise`) the same way Egregoria's layer-based window system
+/// keeps the focused panel frontmost.
+#[derive(Resource)]
+pub struct WindowManager {
+    order: Vec<WindowId>,
+    open: std::collections::HashMap<WindowId, bool>,
+    positions: std::collections::HashMap<WindowId, egui::Pos2>,
+}
+
+impl Default for WindowManager {
+    fn default() -> Self {
+        let open = [
+            (WindowId::Status, true),
+            (WindowId::Tools, true),
+            (WindowId::Inspector, false),
+            (WindowId::Stats, false),
+            (WindowId::News, true),
+        ]
+        .into_iter()
+        .collect();
+
+        Self {
+            order: ALL_WINDOW_IDS.to_vec(),
+            open,
+            positions: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl WindowManager {
+    pub fn is_open(&self, id: WindowId) -> bool {
+        *self.open.get(&id).unwrap_or(&false)
+    }
+
+    pub fn set_open(&mut self, id: WindowId, open: bool) {
+        self.open.insert(id, open);
+    }
+
+    pub fn toggle(&mut self, id: WindowId) {
+        let open = !self.is_open(id);
+        self.set_open(id, open);
+        if open {
+            self.raise(id);
+        }
+    }
+
+    /// Opens `id` and brings it to the front, e.g. when a click spawns or refocuses it.
+    pub fn open_and_raise(&mut self, id: WindowId) {
+        self.set_open(id, true);
+        self.raise(id);
+    }
+
+    /// Moves `id` to the end of the draw order, making it the last (and therefore top-most)
+    /// window shown next frame.
+    fn raise(&mut self, id: WindowId) {
+        self.order.retain(|&w| w != id);
+        self.order.push(id);
+    }
+
+    /// Shows `id`'s window if open, remembering its on-screen position across frames and raising
+    /// it to front on interaction. `add_contents` builds the window body.
+    fn show(&mut self, ctx: &egui::Context, id: WindowId, add_contents: impl FnOnce(&mut egui::Ui)) {
+        if !self.is_open(id) {
+            return;
+        }
+
+        let mut open = true;
+        let mut window = egui::Window::new(id.title()).open(&mut open);
+        if let Some(&pos) = self.positions.get(&id) {
+            window = window.default_pos(pos);
+        }
+
+        if let Some(response) = window.show(ctx, add_contents) {
+            self.positions.insert(id, response.response.rect.min);
+            if response.response.clicked() || response.response.dragged() {
+                self.raise(id);
+            }
+        }
+
+        if !open {
+            self.set_open(id, false);
+        }
+    }
+}
+
+fn stats_hotkey_system(mut manager: ResMut<WindowManager>, keys: Res<ButtonInput<KeyCode>>) {
+    // Bare `B` already selects the Bulldozer tool (see `tool_hotkey_system`), so statistics use
+    // Shift+B rather than stealing that letter out from under the Tools panel.
+    let shift = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    if shift && keys.just_pressed(KeyCode::KeyB) {
+        manager.toggle(WindowId::Stats);
+    }
 }
 
 pub struct UiPlugin;
@@ -28,130 +481,103 @@ impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MenuState>()
             .init_resource::<ToolState>()
+            .init_resource::<BrushSize>()
+            .init_resource::<FillTarget>()
+            .init_resource::<DrawMode>()
             .init_resource::<InspectorState>()
+            .init_resource::<NewsLog>()
+            .init_resource::<WindowManager>()
             .add_systems(Update, main_menu_system.run_if(in_state(GameState::Menu)))
             .add_systems(
                 Update,
-                (game_hud_system, inspector_system).run_if(in_state(GameState::Game)),
+                (
+                    news_event_collector_system,
+                    tool_hotkey_system,
+                    stats_hotkey_system,
+                    window_dispatcher_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Game)),
             );
     }
 }
 
-// Resource to store inspector state
+// Rolling feed of player-facing notifications (currently just zone abandonment), fed by
+// `CityEvent` the same way `InspectorState` is fed by the raycast cursor.
+const NEWS_LOG_CAPACITY: usize = 50;
+
 #[derive(Resource, Default)]
-pub struct InspectorState {
-    pub visible: bool,
-    pub tile_info: Option<(String, String)>, // (Title, Details)
-    pub screen_pos: Vec2,
+pub struct NewsLog {
+    pub messages: Vec<String>,
 }
 
-fn inspector_system(
-    mut contexts: EguiContexts,
-    mut inspector: ResMut<InspectorState>,
-    mouse: Res<ButtonInput<MouseButton>>,
+/// Drains `CityEvent`s into `NewsLog` regardless of whether the News window is currently open, so
+/// nothing is lost while it's closed. Rendering happens separately in `window_dispatcher_system`.
+fn news_event_collector_system(
+    mut news: ResMut<NewsLog>,
+    mut events: EventReader<crate::simulation::CityEvent>,
 ) {
-    if mouse.just_pressed(MouseButton::Right) && !inspector.visible {
-        // This is handled in main.rs -> handle_interaction to calculate tile info
-        // We just verify visibility here or close it
-    }
-
-    if inspector.visible {
-        let mut open = inspector.visible;
-        egui::Window::new(
-            inspector
-                .tile_info
-                .as_ref()
-                .map(|(t, _)| t.as_str())
-                .unwrap_or("Inspector"),
-        )
-        .open(&mut open)
-        .default_pos([inspector.screen_pos.x, inspector.screen_pos.y])
-        .show(contexts.ctx_mut(), |ui| {
-            if let Some((_, details)) = &inspector.tile_info {
-                ui.label(details);
-            }
-        });
-        inspector.visible = open;
+    for event in events.read() {
+        news.messages
+            .push(format!("{} at ({}, {})", event.cause, event.x, event.y));
+        if news.messages.len() > NEWS_LOG_CAPACITY {
+            news.messages.remove(0);
+        }
     }
 }
 
-fn game_hud_system(
-    mut contexts: EguiContexts,
-    mut view_mode: ResMut<ViewMode>,
-    mut tool_state: ResMut<ToolState>,
-    mut grid_state: ResMut<crate::rendering::GridState>,
-    mut sim_state: ResMut<crate::simulation::SimulationState>,
-    diagnostics: Res<bevy::diagnostic::DiagnosticsStore>,
+/// Inspector state fed by the raycast cursor in `main::handle_interaction`. `pending_position` is
+/// set once when a new tile is inspected and consumed by `window_dispatcher_system` to pop the
+/// window up where the player clicked, without fighting the player if they later drag it away.
+#[derive(Resource, Default)]
+pub struct InspectorState {
+    pub tile_info: Option<(String, String)>, // (Title, Details)
+    pub pending_position: Option<Vec2>,
+}
+
+fn game_hud_status_content(
+    ui: &mut egui::Ui,
+    sim_state: &SimState<'_>,
+    diagnostics: &bevy::diagnostic::DiagnosticsStore,
 ) {
-    // Status Panel
-    egui::Window::new("City Status")
-        .anchor(egui::Align2::RIGHT_TOP, [-10.0, 10.0])
-        .show(contexts.ctx_mut(), |ui| {
-            ui.heading("Status");
-            // Time Calculation
-            let total_ticks = sim_state.time;
-            let year = 1900 + (total_ticks / 34560);
-            let month_idx = (total_ticks % 34560) / 2880;
-            let day = ((total_ticks % 2880) / 96) + 1;
-            let hour = (total_ticks % 96) / 4;
-            let minute = (total_ticks % 4) * 15;
-
-            let months = [
-                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
-            ];
-            let month_name = months[month_idx as usize];
-
-            ui.label(format!("Date: {} {} {}", day, month_name, year));
-            ui.label(format!("Time: {:02}:{:02}", hour, minute));
-            ui.separator();
-
-            // Performance
-            if let Some(fps) = diagnostics.get(&bevy::diagnostic::FrameTimeDiagnosticsPlugin::FPS) {
-                if let Some(avg) = fps.average() {
-                    ui.label(format!("FPS: {:.1}", avg));
-                }
-            }
-            if let Some(entities) =
-                diagnostics.get(&bevy::diagnostic::EntityCountDiagnosticsPlugin::ENTITY_COUNT)
-            {
-                if let Some(count) = entities.value() {
-                    ui.label(format!("Entities: {:.0}", count));
-                }
-            }
-        });
+    ui.heading("Status");
+    // Time Calculation
+    let total_ticks = sim_state.time;
+    let year = 1900 + (total_ticks / 34560);
+    let month_idx = (total_ticks % 34560) / 2880;
+    let day = ((total_ticks % 2880) / 96) + 1;
+    let hour = (total_ticks % 96) / 4;
+    let minute = (total_ticks % 4) * 15;
 
-    // Tools Panel
-    egui::Window::new("Tools")
-        .anchor(egui::Align2::LEFT_TOP, [10.0, 10.0])
-        .show(contexts.ctx_mut(), |ui| {
-            ui.label("Simulation");
-            ui.add(egui::Slider::new(&mut sim_state.growth_rate, 0.0..=10.0).text("Growth Rate"));
-            ui.separator();
+    let months = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let month_name = months[month_idx as usize];
 
-            ui.label("View Layers");
-            ui.horizontal(|ui| {
-                ui.radio_value(&mut *view_mode, ViewMode::Surface, "Surface");
-                ui.radio_value(&mut *view_mode, ViewMode::Underground, "Underground");
-            });
-            ui.checkbox(&mut grid_state.visible, "Show Grid (G)");
-            ui.separator();
-
-            ui.label("Tools");
-            ui.horizontal_wrapped(|ui| {
-                ui.selectable_value(&mut *tool_state, ToolState::Select, "👆 Select");
-                ui.selectable_value(&mut *tool_state, ToolState::Bulldozer, "🚜 Doze");
-                ui.selectable_value(&mut *tool_state, ToolState::Road, "🛣️ Road");
-            });
-            ui.horizontal_wrapped(|ui| {
-                ui.selectable_value(&mut *tool_state, ToolState::Residential, "🏠 Res");
-                ui.selectable_value(&mut *tool_state, ToolState::Commercial, "🏢 Com");
-                ui.selectable_value(&mut *tool_state, ToolState::Industrial, "🏭 Ind");
-            });
-            ui.horizontal_wrapped(|ui| {
-                ui.selectable_value(&mut *tool_state, ToolState::PowerPlant, "⚡ Plant");
-                ui.selectable_value(&mut *tool_state, ToolState::PowerLine, "🔌 Line");
-            });
-        });
+    ui.label(format!("Date: {} {} {}", day, month_name, year));
+    ui.label(format!("Time: {:02}:{:02}", hour, minute));
+    ui.separator();
+
+    // Performance
+    if let Some(fps) = diagnostics.get(&bevy::diagnostic::FrameTimeDiagnosticsPlugin::FPS) {
+        if let Some(avg) = fps.average() {
+            ui.label(format!("FPS: {:.1}", avg));
+        }
+    }
+    if let Some(entities) =
+        diagnostics.get(&bevy::diagnostic::EntityCountDiagnosticsPlugin::ENTITY_COUNT)
+    {
+        if let Some(count) = entities.value() {
+            ui.label(format!("Entities: {:.0}", count));
+        }
+    }
+}
+
+/// Borrowed fields `game_hud_status_content` needs from `SimulationState`, so the closure doesn't
+/// have to hold a full `ResMut` borrow across the dispatcher's match.
+struct SimState<'a> {
+    time: u64,
+    growth_rate: &'a mut f32,
 }
 
 fn main_menu_system(
@@ -159,6 +585,7 @@ fn main_menu_system(
     mut menu_state: ResMut<MenuState>,
     mut next_state: ResMut<NextState<GameState>>,
     mut game_map: ResMut<GameMap>,
+    mut sim_state: ResMut<crate::simulation::SimulationState>,
 ) {
     egui::CentralPanel::default().show(contexts.ctx_mut(), |ui| {
         ui.vertical_centered(|ui| {
@@ -172,7 +599,21 @@ fn main_menu_system(
                 }
 
                 if ui.button("Load Game").clicked() {
-                    // TODO: Load Logic
+                    match crate::persistence::load_city(SAVE_PATH) {
+                        Ok((loaded_map, loaded_sim_state)) => {
+                            *game_map = loaded_map;
+                            *sim_state = loaded_sim_state;
+                            menu_state.status = None;
+                            next_state.set(GameState::Game);
+                        }
+                        Err(err) => {
+                            menu_state.status = Some(format!("Load failed: {err}"));
+                        }
+                    }
+                }
+
+                if let Some(status) = &menu_state.status {
+                    ui.colored_label(egui::Color32::LIGHT_RED, status);
                 }
 
                 if ui.button("Exit").clicked() {
@@ -208,12 +649,38 @@ fn main_menu_system(
                         );
                     });
 
+                    // Terrain Shape (fBm octaves/persistence/lacunarity, river count)
+                    ui.horizontal(|ui| {
+                        ui.label("Octaves:");
+                        ui.add(egui::Slider::new(&mut menu_state.octaves, 1..=8));
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Persistence:");
+                        ui.add(egui::Slider::new(&mut menu_state.persistence, 0.1..=0.9));
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Lacunarity:");
+                        ui.add(egui::Slider::new(&mut menu_state.lacunarity, 1.5..=3.0));
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Rivers:");
+                        ui.add(egui::Slider::new(&mut menu_state.river_count, 0..=10));
+                    });
+
                     ui.add_space(20.0);
 
                     ui.horizontal(|ui| {
                         if ui.button("Start Simulation").clicked() {
                             let size = sizes[menu_state.map_size_idx];
-                            *game_map = GameMap::new(size, size, menu_state.water_percent);
+                            *game_map = GameMap::new(
+                                size,
+                                size,
+                                menu_state.water_percent,
+                                menu_state.octaves,
+                                menu_state.persistence,
+                                menu_state.lacunarity,
+                                menu_state.river_count,
+                            );
                             next_state.set(GameState::Game);
                             menu_state.show_config = false; // Reset for next time
                         }
@@ -227,3 +694,264 @@ fn main_menu_system(
         });
     });
 }
+
+/// Central dispatcher for every floating panel: walks `WindowManager`'s draw order and shows each
+/// open window through `WindowManager::show`, so z-order/focus-raising lives in one place instead
+/// of each panel managing its own visibility bookkeeping. Also renders the taskbar strip used to
+/// reopen a closed window.
+#[allow(clippy::too_many_arguments)]
+fn window_dispatcher_system(
+    mut contexts: EguiContexts,
+    mut manager: ResMut<WindowManager>,
+    mut view_mode: ResMut<ViewMode>,
+    mut tool_state: ResMut<ToolState>,
+    mut brush_size: ResMut<BrushSize>,
+    mut fill_target: ResMut<FillTarget>,
+    mut grid_state: ResMut<crate::rendering::GridState>,
+    mut layer_transparency: ResMut<crate::rendering::LayerTransparency>,
+    mut sim_state: ResMut<crate::simulation::SimulationState>,
+    mut menu_state: ResMut<MenuState>,
+    diagnostics: Res<bevy::diagnostic::DiagnosticsStore>,
+    mut inspector: ResMut<InspectorState>,
+    news: Res<NewsLog>,
+    map: Res<GameMap>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    for id in manager.order.clone() {
+        match id {
+            WindowId::Status => {
+                let mut sim = SimState {
+                    time: sim_state.time,
+                    growth_rate: &mut sim_state.growth_rate,
+                };
+                manager.show(ctx, id, |ui| {
+                    game_hud_status_content(ui, &sim, &diagnostics);
+                });
+                let _ = &mut sim; // borrow lives only for the closure above
+            }
+            WindowId::Tools => {
+                manager.show(ctx, id, |ui| {
+                    ui.label("Simulation");
+                    ui.add(
+                        egui::Slider::new(&mut sim_state.growth_rate, 0.0..=10.0)
+                            .text("Growth Rate"),
+                    );
+                    ui.separator();
+
+                    ui.label("View Layers");
+                    ui.horizontal(|ui| {
+                        ui.radio_value(&mut *view_mode, ViewMode::Surface, "Surface");
+                        ui.radio_value(&mut *view_mode, ViewMode::Underground, "Underground");
+                        ui.radio_value(&mut *view_mode, ViewMode::Air, "Air");
+                    });
+                    ui.checkbox(&mut grid_state.visible, "Show Grid (G)");
+                    ui.separator();
+
+                    ui.label("Layer Transparency (T toggles Underground + Air)");
+                    for (label, slot) in [("Underground", 0usize), ("Surface", 1), ("Air", 2)] {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(
+                                &mut layer_transparency.transparent[slot],
+                                format!("{label} Transparent"),
+                            );
+                            ui.add(
+                                egui::Slider::new(&mut layer_transparency.layer_alpha[slot], 0.0..=1.0)
+                                    .text("Alpha"),
+                            );
+                        });
+                    }
+                    ui.separator();
+
+                    ui.label("Tools");
+                    ui.add(egui::Slider::new(&mut brush_size.0, 1..=7).text("Brush Size"));
+                    ui.small("Hold Shift to axis-lock a drag, Ctrl to paint freehand.");
+                    ui.separator();
+
+                    if ui.button("💾 Save City").clicked() {
+                        menu_state.status = match crate::persistence::save_city(
+                            &map,
+                            &sim_state,
+                            SAVE_PATH,
+                        ) {
+                            Ok(()) => Some(format!("Saved to {SAVE_PATH}")),
+                            Err(err) => Some(format!("Save failed: {err}")),
+                        };
+                    }
+                    if let Some(status) = &menu_state.status {
+                        ui.small(status);
+                    }
+                    tool_category_combo(ui, "edit_tools", "Edit", EDIT_TOOLS, &mut tool_state);
+                    tool_category_combo(ui, "zone_tools", "Zones", ZONE_TOOLS, &mut tool_state);
+                    tool_category_combo(
+                        ui,
+                        "infrastructure_tools",
+                        "Infrastructure",
+                        INFRASTRUCTURE_TOOLS,
+                        &mut tool_state,
+                    );
+                    ui.label("Underground");
+                    ui.horizontal_wrapped(|ui| {
+                        ui.selectable_value(&mut *tool_state, ToolState::Subway, "🚇 Subway");
+                        ui.selectable_value(&mut *tool_state, ToolState::Pipe, "🚰 Pipe");
+                    });
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        ui.label("Fill Target:");
+                        let selected_text = FILL_TARGETS
+                            .iter()
+                            .find(|(tile, _)| *tile == fill_target.0)
+                            .map(|(_, label)| *label)
+                            .unwrap_or("?");
+                        egui::ComboBox::from_id_salt("fill_target")
+                            .selected_text(selected_text)
+                            .show_ui(ui, |ui| {
+                                for (tile, label) in FILL_TARGETS {
+                                    ui.selectable_value(&mut fill_target.0, *tile, *label);
+                                }
+                            });
+                    });
+                });
+            }
+            WindowId::Inspector => {
+                let Some((_, details)) = inspector.tile_info.clone() else {
+                    continue;
+                };
+                if let Some(pos) = inspector.pending_position.take() {
+                    manager
+                        .positions
+                        .insert(id, egui::pos2(pos.x, pos.y));
+                }
+                manager.show(ctx, id, |ui| {
+                    ui.label(&details);
+                });
+            }
+            WindowId::Stats => {
+                let Some(surface) = map.layers.get(&0) else {
+                    continue;
+                };
+
+                let residential = count_zone(
+                    surface,
+                    TileType::Residential,
+                    TileType::ResidentialOccupied1,
+                    TileType::ResidentialOccupied2,
+                    TileType::ResidentialOccupied3,
+                );
+                let commercial = count_zone(
+                    surface,
+                    TileType::Commercial,
+                    TileType::CommercialOccupied1,
+                    TileType::CommercialOccupied2,
+                    TileType::CommercialOccupied3,
+                );
+                let industrial = count_zone(
+                    surface,
+                    TileType::Industrial,
+                    TileType::IndustrialOccupied1,
+                    TileType::IndustrialOccupied2,
+                    TileType::IndustrialOccupied3,
+                );
+
+                let road = surface.iter().filter(|&&t| crate::map::is_road(t)).count();
+                // Power lines are only ever stamped onto the Air layer (see `stamp_at`), not Surface.
+                let power_line = map
+                    .layers
+                    .get(&1)
+                    .map(|air| air.iter().filter(|&&t| t == TileType::PowerLine).count())
+                    .unwrap_or(0);
+                let power_plant = surface
+                    .iter()
+                    .filter(|&&t| {
+                        matches!(
+                            t,
+                            TileType::PowerPlantCoal
+                                | TileType::PowerPlantGas
+                                | TileType::PowerPlantNuclear
+                                | TileType::PowerPlantSolar
+                                | TileType::PowerPlantWind
+                        )
+                    })
+                    .count();
+                let water = surface.iter().filter(|&&t| t == TileType::Water).count();
+                let dirt = surface.iter().filter(|&&t| t == TileType::Dirt).count();
+
+                manager.show(ctx, id, |ui| {
+                    egui::Grid::new("zone_stats_grid")
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label("Zone");
+                            ui.label("Empty");
+                            ui.label("Lvl 1");
+                            ui.label("Lvl 2");
+                            ui.label("Lvl 3");
+                            ui.label("Developed");
+                            ui.end_row();
+
+                            for (name, counts) in [
+                                ("Residential", &residential),
+                                ("Commercial", &commercial),
+                                ("Industrial", &industrial),
+                            ] {
+                                ui.label(name);
+                                ui.label(counts.empty.to_string());
+                                ui.label(counts.level1.to_string());
+                                ui.label(counts.level2.to_string());
+                                ui.label(counts.level3.to_string());
+                                let total = counts.total();
+                                let fraction = if total > 0 {
+                                    counts.developed() as f32 / total as f32
+                                } else {
+                                    0.0
+                                };
+                                ui.add(
+                                    egui::ProgressBar::new(fraction)
+                                        .text(format!("{:.0}%", fraction * 100.0)),
+                                );
+                                ui.end_row();
+                            }
+                        });
+
+                    ui.separator();
+                    ui.label(format!("Road: {road}"));
+                    ui.label(format!("Power Line: {power_line}"));
+                    ui.label(format!("Power Plant: {power_plant}"));
+                    ui.label(format!("Water: {water}"));
+                    ui.label(format!("Dirt: {dirt}"));
+                });
+            }
+            WindowId::News => {
+                manager.show(ctx, id, |ui| {
+                    if news.messages.is_empty() {
+                        ui.label("No news yet.");
+                    }
+                    for msg in news.messages.iter().rev().take(10) {
+                        ui.label(msg);
+                    }
+                });
+            }
+        }
+    }
+
+    // Taskbar: lets the player reopen any window they've closed. Not itself manager-tracked, to
+    // avoid having to dispatch to itself.
+    egui::Window::new("Windows")
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -10.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                for id in ALL_WINDOW_IDS {
+                    let mut open = manager.is_open(id);
+                    if ui.selectable_label(open, id.title()).clicked() {
+                        open = !open;
+                        manager.set_open(id, open);
+                        if open {
+                            manager.raise(id);
+                        }
+                    }
+                }
+            });
+        });
+}